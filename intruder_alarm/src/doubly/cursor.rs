@@ -0,0 +1,358 @@
+//! A cursor over a [`List`], allowing in-place traversal, insertion, and
+//! removal.
+//!
+//! [`List`]: ../struct.List.html
+use super::{Link, Linked, Links, List, OwningRef};
+use core::ops::DerefMut;
+
+/// A cursor over a [`List`] that allows inspecting, inserting, and removing
+/// elements at an arbitrary position without walking the list again from
+/// the head.
+///
+/// A cursor can point at a node, or at the "ghost" position past either end
+/// of the list; advancing past the last node (or retreating past the first)
+/// moves the cursor to the ghost position, and advancing again wraps around
+/// to the other end, so a full circular walk of the list is always
+/// possible.
+///
+/// Created by [`List::cursor_front_mut`] or [`List::cursor_back_mut`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::cursor_front_mut`]: ../struct.List.html#method.cursor_front_mut
+/// [`List::cursor_back_mut`]: ../struct.List.html#method.cursor_back_mut
+pub struct CursorMut<'a, T: 'a, N: 'a, R: 'a> {
+    list: &'a mut List<T, N, R>,
+    current: Link<N>,
+    index: usize,
+}
+
+impl<T, N, R> List<T, N, R> {
+    /// Returns a cursor positioned at the front of the list.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N, R> {
+        CursorMut {
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    /// Returns a cursor positioned at the back of the list.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N, R> {
+        let index = self.len.wrapping_sub(1);
+        CursorMut {
+            current: self.tail,
+            index,
+            list: self,
+        }
+    }
+}
+
+impl<'a, T, N, R> CursorMut<'a, T, N, R>
+where
+    N: Linked,
+{
+    /// Advances the cursor to the next node in the list.
+    ///
+    /// If the cursor was at the ghost position, it moves to the front of
+    /// the list. If the cursor was at the last node, it moves to the ghost
+    /// position.
+    pub fn move_next(&mut self) {
+        match self.current.as_ptr() {
+            Some(ptr) => unsafe {
+                self.current = (*ptr).links().next;
+                self.index = if self.current.0.is_some() {
+                    self.index + 1
+                } else {
+                    self.list.len
+                };
+            },
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous node in the list.
+    ///
+    /// If the cursor was at the ghost position, it moves to the back of
+    /// the list. If the cursor was at the first node, it moves to the
+    /// ghost position.
+    pub fn move_prev(&mut self) {
+        match self.current.as_ptr() {
+            Some(ptr) => unsafe {
+                self.current = (*ptr).links().prev;
+                self.index = if self.current.0.is_some() {
+                    self.index.wrapping_sub(1)
+                } else {
+                    self.list.len
+                };
+            },
+            None => {
+                self.current = self.list.tail;
+                self.index = self.list.len.wrapping_sub(1);
+            }
+        }
+    }
+
+    /// Returns the index of the cursor's current position, or `None` if
+    /// the cursor is at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.0.is_some() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, N, R> CursorMut<'a, T, N, R>
+where
+    N: Linked + AsMut<T>,
+{
+    /// Returns a mutable reference to the element at the cursor's current
+    /// position, or `None` if the cursor is at the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.as_mut().map(N::as_mut)
+    }
+}
+
+impl<'a, T, N, R> CursorMut<'a, T, N, R>
+where
+    N: Linked + AsRef<T>,
+{
+    /// Returns a reference to the next element in the list, without moving
+    /// the cursor.
+    ///
+    /// This mirrors what a subsequent [`move_next`] would land on: `None`
+    /// if the cursor is already on the last node (one more `move_next`
+    /// would reach the ghost position), and the front of the list (`None`
+    /// if it is empty) when the cursor is at the ghost position itself.
+    ///
+    /// [`move_next`]: #method.move_next
+    pub fn peek_next(&self) -> Option<&T> {
+        match self.current.as_ptr() {
+            Some(ptr) => unsafe { (*ptr).next() }.map(N::as_ref),
+            None => self.list.head().map(N::as_ref),
+        }
+    }
+
+    /// Returns a reference to the previous element in the list, without
+    /// moving the cursor.
+    ///
+    /// This mirrors what a subsequent [`move_prev`] would land on: `None`
+    /// if the cursor is already on the first node (one more `move_prev`
+    /// would reach the ghost position), and the back of the list (`None`
+    /// if it is empty) when the cursor is at the ghost position itself.
+    ///
+    /// [`move_prev`]: #method.move_prev
+    pub fn peek_prev(&self) -> Option<&T> {
+        match self.current.as_ptr() {
+            Some(ptr) => unsafe { (*ptr).prev() }.map(N::as_ref),
+            None => self.list.tail().map(N::as_ref),
+        }
+    }
+}
+
+impl<'a, T, N, R> CursorMut<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N> + DerefMut,
+{
+    /// Removes the node at the cursor's current position and returns its
+    /// owning [`Ref`], advancing the cursor to the node that followed it
+    /// (or to the ghost position, if the removed node was the last one).
+    ///
+    /// Returns `None`, without moving the cursor, if it is at the ghost
+    /// position.
+    ///
+    /// [`Ref`]: ../trait.OwningRef.html
+    pub fn remove_current(&mut self) -> Option<R> {
+        let ptr = self.current.as_ptr()?;
+        unsafe {
+            let next = (*ptr).links().next;
+            let prev = (*ptr).links().prev;
+
+            match next.as_ptr() {
+                Some(next_ptr) => (*next_ptr).links_mut().prev = prev,
+                None => self.list.tail = prev,
+            }
+            match prev.as_ptr() {
+                Some(prev_ptr) => (*prev_ptr).links_mut().next = next,
+                None => self.list.head = next,
+            }
+
+            self.list.len -= 1;
+            self.current = next;
+            if self.current.0.is_none() {
+                self.index = self.list.len;
+            }
+
+            Some(R::from_ptr(ptr as *const N))
+        }
+    }
+
+    /// Inserts `node` immediately after the cursor's current position.
+    ///
+    /// If the cursor is at the ghost position, `node` is pushed onto the
+    /// front of the list instead.
+    pub fn insert_after_node(&mut self, mut node: R) {
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let next = (*ptr).links().next;
+                    *node.links_mut() = Links {
+                        next,
+                        prev: self.current,
+                    };
+                    let node = Link::from_owning_ref(node);
+
+                    match next.as_ptr() {
+                        Some(next_ptr) => (*next_ptr).links_mut().prev = node,
+                        None => self.list.tail = node,
+                    }
+                    (*ptr).links_mut().next = node;
+                    self.list.len += 1;
+                }
+                None => {
+                    self.list.push_front_node(node);
+                }
+            }
+        }
+    }
+
+    /// Inserts `node` immediately before the cursor's current position.
+    ///
+    /// If the cursor is at the ghost position, `node` is pushed onto the
+    /// back of the list instead.
+    pub fn insert_before_node(&mut self, mut node: R) {
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let prev = (*ptr).links().prev;
+                    *node.links_mut() = Links {
+                        next: self.current,
+                        prev,
+                    };
+                    let node = Link::from_owning_ref(node);
+
+                    match prev.as_ptr() {
+                        Some(prev_ptr) => (*prev_ptr).links_mut().next = node,
+                        None => self.list.head = node,
+                    }
+                    (*ptr).links_mut().prev = node;
+                    self.list.len += 1;
+                    self.index += 1;
+                }
+                None => {
+                    self.list.push_back_node(node);
+                }
+            }
+        }
+    }
+
+    /// Moves all of `other`'s nodes into this cursor's list, in order,
+    /// immediately after the cursor's current position, in O(1). `other`
+    /// is left empty.
+    ///
+    /// If the cursor is at the ghost position, `other`'s nodes are spliced
+    /// onto the front of the list instead.
+    pub fn splice_after(&mut self, other: &mut List<T, N, R>) {
+        if other.is_empty() {
+            return;
+        }
+        let (other_head, other_tail, other_len) = (other.head, other.tail, other.len);
+        other.head = Link::none();
+        other.tail = Link::none();
+        other.len = 0;
+
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let next = (*ptr).links().next;
+
+                    (*ptr).links_mut().next = other_head;
+                    (*other_head.as_ptr().expect("non-empty list has a head")).links_mut().prev =
+                        self.current;
+
+                    match next.as_ptr() {
+                        Some(next_ptr) => {
+                            (*next_ptr).links_mut().prev = other_tail;
+                            (*other_tail.as_ptr().expect("non-empty list has a tail"))
+                                .links_mut()
+                                .next = next;
+                        }
+                        None => self.list.tail = other_tail,
+                    }
+                }
+                None => match self.list.head.as_ptr() {
+                    Some(head_ptr) => {
+                        (*head_ptr).links_mut().prev = other_tail;
+                        (*other_tail.as_ptr().expect("non-empty list has a tail")).links_mut().next =
+                            self.list.head;
+                        self.list.head = other_head;
+                    }
+                    None => {
+                        self.list.head = other_head;
+                        self.list.tail = other_tail;
+                    }
+                },
+            }
+        }
+
+        self.list.len += other_len;
+    }
+
+    /// Moves all of `other`'s nodes into this cursor's list, in order,
+    /// immediately before the cursor's current position, in O(1). `other`
+    /// is left empty.
+    ///
+    /// If the cursor is at the ghost position, `other`'s nodes are spliced
+    /// onto the back of the list instead.
+    pub fn splice_before(&mut self, other: &mut List<T, N, R>) {
+        if other.is_empty() {
+            return;
+        }
+        let (other_head, other_tail, other_len) = (other.head, other.tail, other.len);
+        other.head = Link::none();
+        other.tail = Link::none();
+        other.len = 0;
+
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let prev = (*ptr).links().prev;
+
+                    (*ptr).links_mut().prev = other_tail;
+                    (*other_tail.as_ptr().expect("non-empty list has a tail")).links_mut().next =
+                        self.current;
+
+                    match prev.as_ptr() {
+                        Some(prev_ptr) => {
+                            (*prev_ptr).links_mut().next = other_head;
+                            (*other_head.as_ptr().expect("non-empty list has a head"))
+                                .links_mut()
+                                .prev = prev;
+                        }
+                        None => self.list.head = other_head,
+                    }
+                    self.index = self.index.wrapping_add(other_len);
+                }
+                None => match self.list.tail.as_ptr() {
+                    Some(tail_ptr) => {
+                        (*tail_ptr).links_mut().next = other_head;
+                        (*other_head.as_ptr().expect("non-empty list has a head")).links_mut().prev =
+                            self.list.tail;
+                        self.list.tail = other_tail;
+                    }
+                    None => {
+                        self.list.head = other_head;
+                        self.list.tail = other_tail;
+                    }
+                },
+            }
+        }
+
+        self.list.len += other_len;
+    }
+}
@@ -0,0 +1,428 @@
+//! Iterators over [`List`]'s elements.
+//!
+//! [`List`]: ../struct.List.html
+use super::{Link, Linked, List, OwningRef};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// An iterator over references to the items stored in a [`List`].
+///
+/// Created by [`List::iter`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::iter`]: ../struct.List.html#method.iter
+pub struct Iter<'a, T: 'a, N: 'a> {
+    head: Option<&'a N>,
+    tail: Option<&'a N>,
+    len: usize,
+    _elem_ty: PhantomData<&'a T>,
+}
+
+/// An iterator over mutable references to the items stored in a [`List`].
+///
+/// Created by [`List::iter_mut`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::iter_mut`]: ../struct.List.html#method.iter_mut
+pub struct IterMut<'a, T: 'a, N: 'a> {
+    head: Link<N>,
+    tail: Link<N>,
+    len: usize,
+    _elem_ty: PhantomData<&'a mut T>,
+}
+
+/// An owning iterator over the items of a [`List`].
+///
+/// Created by [`List`]'s `IntoIterator` implementation.
+///
+/// [`List`]: ../struct.List.html
+pub struct IntoIter<T, N, R> {
+    list: List<T, N, R>,
+}
+
+/// A draining iterator that pops every node out of a [`List`] from the
+/// front, leaving it empty.
+///
+/// Created by [`List::drain`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::drain`]: ../struct.List.html#method.drain
+pub struct Drain<'a, T: 'a, N: 'a, R: 'a>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    list: &'a mut List<T, N, R>,
+}
+
+/// A lazy iterator that removes and yields each item of a [`List`] for
+/// which the given predicate returns `true`, keeping the rest in place
+/// with their relative order intact.
+///
+/// If the `DrainFilter` is dropped before being fully consumed, its
+/// `Drop` implementation finishes the walk, so every remaining matching
+/// item is still removed.
+///
+/// Created by [`List::drain_filter`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::drain_filter`]: ../struct.List.html#method.drain_filter
+pub struct DrainFilter<'a, T: 'a, N: 'a, R: 'a, F>
+where
+    N: Linked + AsRef<T>,
+    R: OwningRef<N>,
+    F: FnMut(&T) -> bool,
+{
+    list: &'a mut List<T, N, R>,
+    current: Link<N>,
+    pred: F,
+}
+
+// ===== impl List =====
+
+impl<T, N, R> List<T, N, R>
+where
+    N: Linked + AsRef<T>,
+{
+    /// Returns an iterator over references to this list's items, from
+    /// head to tail.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            head: self.head(),
+            tail: self.tail(),
+            len: self.len,
+            _elem_ty: PhantomData,
+        }
+    }
+}
+
+impl<T, N, R> List<T, N, R>
+where
+    N: Linked + AsMut<T>,
+{
+    /// Returns an iterator over mutable references to this list's items,
+    /// from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _elem_ty: PhantomData,
+        }
+    }
+}
+
+impl<T, N, R> List<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    /// Returns a draining iterator that removes each node from the front
+    /// of the list and yields its owning [`Ref`], leaving the list empty
+    /// once the iterator is exhausted or dropped.
+    ///
+    /// [`Ref`]: ../trait.OwningRef.html
+    pub fn drain(&mut self) -> Drain<'_, T, N, R> {
+        Drain { list: self }
+    }
+}
+
+impl<T, N, R> List<T, N, R>
+where
+    N: Linked + AsRef<T>,
+    R: OwningRef<N>,
+{
+    /// Returns a lazy iterator that removes and yields each item for
+    /// which `pred` returns `true`, leaving the rest of the list in
+    /// place with their relative order intact.
+    ///
+    /// The predicate is only called on items that are still in the list,
+    /// so items are visited at most once. If the `DrainFilter` is dropped
+    /// before being fully consumed, the remaining matching items are
+    /// still removed.
+    pub fn drain_filter<F>(&mut self, pred: F) -> DrainFilter<'_, T, N, R, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let current = self.head;
+        DrainFilter {
+            list: self,
+            current,
+            pred,
+        }
+    }
+
+    /// Retains only the items for which `f` returns `true`, removing the
+    /// rest and dropping their owning `Ref`s.
+    ///
+    /// This walks the list once; removing an item relinks its neighbors
+    /// in place and fixes up `head`/`tail` if it was an endpoint.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.drain_filter(move |item| !f(item)).for_each(drop);
+    }
+}
+
+impl<T, N, R> IntoIterator for List<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    type Item = R;
+    type IntoIter = IntoIter<T, N, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+// ===== impl Iter =====
+
+impl<'a, T, N> Iterator for Iter<'a, T, N>
+where
+    N: Linked + AsRef<T> + 'a,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.head?;
+        self.head = node.next();
+        self.len -= 1;
+        Some(node.as_ref())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, N> DoubleEndedIterator for Iter<'a, T, N>
+where
+    N: Linked + AsRef<T> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.tail?;
+        self.tail = node.prev();
+        self.len -= 1;
+        Some(node.as_ref())
+    }
+}
+
+impl<'a, T, N> ExactSizeIterator for Iter<'a, T, N> where N: Linked + AsRef<T> + 'a {}
+impl<'a, T, N> FusedIterator for Iter<'a, T, N> where N: Linked + AsRef<T> + 'a {}
+
+// ===== impl IterMut =====
+
+impl<'a, T, N> Iterator for IterMut<'a, T, N>
+where
+    N: Linked + AsMut<T> + 'a,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.head.as_ptr()?;
+            self.head = (*ptr).links().next;
+            self.len -= 1;
+            Some((&mut *ptr).as_mut())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, N> DoubleEndedIterator for IterMut<'a, T, N>
+where
+    N: Linked + AsMut<T> + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.tail.as_ptr()?;
+            self.tail = (*ptr).links().prev;
+            self.len -= 1;
+            Some((&mut *ptr).as_mut())
+        }
+    }
+}
+
+impl<'a, T, N> ExactSizeIterator for IterMut<'a, T, N> where N: Linked + AsMut<T> + 'a {}
+impl<'a, T, N> FusedIterator for IterMut<'a, T, N> where N: Linked + AsMut<T> + 'a {}
+
+// ===== impl IntoIter =====
+
+impl<T, N, R> Iterator for IntoIter<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front_node()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, N, R> DoubleEndedIterator for IntoIter<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back_node()
+    }
+}
+
+impl<T, N, R> ExactSizeIterator for IntoIter<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+}
+
+impl<T, N, R> FusedIterator for IntoIter<T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+}
+
+// ===== impl Drain =====
+
+impl<'a, T, N, R> Iterator for Drain<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front_node()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T, N, R> DoubleEndedIterator for Drain<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back_node()
+    }
+}
+
+impl<'a, T, N, R> ExactSizeIterator for Drain<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+}
+
+impl<'a, T, N, R> FusedIterator for Drain<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+}
+
+impl<'a, T, N, R> Drop for Drain<'a, T, N, R>
+where
+    N: Linked,
+    R: OwningRef<N>,
+{
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+    }
+}
+
+// ===== impl DrainFilter =====
+
+impl<'a, T, N, R, F> Iterator for DrainFilter<'a, T, N, R, F>
+where
+    N: Linked + AsRef<T>,
+    R: OwningRef<N>,
+    F: FnMut(&T) -> bool,
+{
+    type Item = R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(ptr) = self.current.as_ptr() {
+            unsafe {
+                let next = (*ptr).links().next;
+
+                if !(self.pred)((*ptr).as_ref()) {
+                    self.current = next;
+                    continue;
+                }
+
+                let prev = (*ptr).links().prev;
+                match next.as_ptr() {
+                    Some(next_ptr) => (*next_ptr).links_mut().prev = prev,
+                    None => self.list.tail = prev,
+                }
+                match prev.as_ptr() {
+                    Some(prev_ptr) => (*prev_ptr).links_mut().next = next,
+                    None => self.list.head = next,
+                }
+
+                self.list.len -= 1;
+                self.current = next;
+
+                return Some(R::from_ptr(ptr as *const N));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, T, N, R, F> FusedIterator for DrainFilter<'a, T, N, R, F>
+where
+    N: Linked + AsRef<T>,
+    R: OwningRef<N>,
+    F: FnMut(&T) -> bool,
+{
+}
+
+impl<'a, T, N, R, F> Drop for DrainFilter<'a, T, N, R, F>
+where
+    N: Linked + AsRef<T>,
+    R: OwningRef<N>,
+    F: FnMut(&T) -> bool,
+{
+    fn drop(&mut self) {
+        while self.next().is_some() {}
+    }
+}
@@ -0,0 +1,688 @@
+//  SOS: the Stupid Operating System
+//  by Eliza Weisman (eliza@elizas.website)
+//
+//  Copyright (c) 2015-2017 Eliza Weisman
+//  Released under the terms of the MIT license. See `LICENSE` in the root
+//  directory of this repository for more information.
+//
+//! An intrusive doubly-linked list.
+//!
+//! Unlike the list in [`singly`], each node here stores a single [`Links`]
+//! bundle holding both its `next` and `prev` pointers, rather than two
+//! separate [`Link`]s. This gives every node a fixed, uniform shape and lets
+//! `push_back_node`/`pop_back_node`/`tail` run in constant time, at the cost
+//! of a node only ever being a member of one list at a time.
+//!
+//! [`singly`]: ../singly/index.html
+use super::{Link, OwningRef};
+pub use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::DerefMut;
+
+mod cursor;
+mod iter;
+pub use self::cursor::CursorMut;
+pub use self::iter::{Drain, DrainFilter, IntoIter, Iter, IterMut};
+
+#[cfg(test)]
+mod tests;
+
+//-----------------------------------------------------------------------------
+// Public API types
+//-----------------------------------------------------------------------------
+
+//  Links
+/// A node's `next` and `prev` [`Link`]s.
+///
+/// [`Link`]: ../struct.Link.html
+#[derive(Debug)]
+pub struct Links<T> {
+    /// Link to the next node in the list.
+    next: Link<T>,
+
+    /// Link to the previous node in the list.
+    prev: Link<T>,
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Links {
+            next: Link::none(),
+            prev: Link::none(),
+        }
+    }
+}
+
+//  List
+/// An intrusive doubly-linked list.
+///
+/// This type is a wrapper around a series of [`Node`]s. It stores [`Link`]s
+/// to the head and tail [`Node`]s and the length of the list.
+///
+/// # Type parameters
+/// - `T`: the type of the items stored by each `Node`
+/// - `Node`: the type of nodes in the list
+/// - `Ref`: the type of [`OwningRef`] that owns each `Node`.
+///
+/// [`Node`]: trait.Linked.html
+/// [`Link`]: ../struct.Link.html
+/// [`OwningRef`]: ../trait.OwningRef.html
+#[derive(Default)]
+pub struct List<T, Node, Ref> {
+    /// Link to the head node of the list.
+    head: Link<Node>,
+
+    /// Link to the tail node of the list.
+    tail: Link<Node>,
+
+    /// Length of the list.
+    len: usize,
+
+    /// Type marker for items stored in the list.
+    _elem_ty: PhantomData<T>,
+
+    /// Type marker for the `OwningRef` type.
+    _ref_ty: PhantomData<Ref>,
+}
+
+//  Linked
+/// Trait that must be implemented in order to be a member of a
+/// doubly-linked intrusive [`List`].
+///
+/// [`List`]: struct.List.html
+pub trait Linked: Sized {
+    /// Borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links(&self) -> &Links<Self>;
+
+    /// Mutably borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links_mut(&mut self) -> &mut Links<Self>;
+
+    /// De-link this node, returning its [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn take_links(&mut self) -> Links<Self> {
+        mem::replace(self.links_mut(), Links::default())
+    }
+
+    /// Borrow the `next` element in the list, or `None` if this is the
+    /// last.
+    #[inline]
+    fn next(&self) -> Option<&Self> {
+        self.links().next.as_ref()
+    }
+
+    /// Mutably borrow the `next` element in the list, or `None` if this is
+    /// the last.
+    #[inline]
+    fn next_mut(&mut self) -> Option<&mut Self> {
+        self.links_mut().next.as_mut()
+    }
+
+    /// Borrow the `prev` element in the list, or `None` if this is the
+    /// first.
+    #[inline]
+    fn prev(&self) -> Option<&Self> {
+        self.links().prev.as_ref()
+    }
+
+    /// Mutably borrow the `prev` element in the list, or `None` if this is
+    /// the first.
+    #[inline]
+    fn prev_mut(&mut self) -> Option<&mut Self> {
+        self.links_mut().prev.as_mut()
+    }
+
+    /// Borrow the `next` linked element, or `None` if this is the last.
+    #[inline]
+    fn peek_next<T>(&self) -> Option<&T>
+    where
+        Self: AsRef<T>,
+    {
+        self.next().map(Self::as_ref)
+    }
+
+    /// Mutably borrow the `next` linked element, or `None` if this is the
+    /// last.
+    #[inline]
+    fn peek_next_mut<T>(&mut self) -> Option<&mut T>
+    where
+        Self: AsMut<T>,
+    {
+        self.next_mut().map(Self::as_mut)
+    }
+
+    /// Borrow the `prev` linked element, or `None` if this is the first.
+    #[inline]
+    fn peek_prev<T>(&self) -> Option<&T>
+    where
+        Self: AsRef<T>,
+    {
+        self.prev().map(Self::as_ref)
+    }
+
+    /// Mutably borrow the `prev` linked element, or `None` if this is the
+    /// first.
+    #[inline]
+    fn peek_prev_mut<T>(&mut self) -> Option<&mut T>
+    where
+        Self: AsMut<T>,
+    {
+        self.prev_mut().map(Self::as_mut)
+    }
+}
+
+//-----------------------------------------------------------------------------
+// Implementations
+//-----------------------------------------------------------------------------
+
+// ===== impl List =====
+
+impl<T, Node, Ref> List<T, Node, Ref> {
+    /// Create a new `List` with 0 elements.
+    pub const fn new() -> Self {
+        List {
+            head: Link::none(),
+            tail: Link::none(),
+            len: 0,
+            _elem_ty: PhantomData,
+            _ref_ty: PhantomData,
+        }
+    }
+
+    /// Returns the length of the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty, false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Borrows the first node of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&Node)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn head(&self) -> Option<&Node> {
+        self.head.as_ref()
+    }
+
+    /// Mutably borrows the first node of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&mut Node)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn head_mut(&mut self) -> Option<&mut Node> {
+        self.head.as_mut()
+    }
+
+    /// Borrows the last node of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&Node)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn tail(&self) -> Option<&Node> {
+        self.tail.as_ref()
+    }
+
+    /// Mutably borrows the last node of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&mut Node)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn tail_mut(&mut self) -> Option<&mut Node> {
+        self.tail.as_mut()
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: Linked,
+    Ref: OwningRef<Node>,
+    Ref: DerefMut,
+{
+    /// Push a node to the head of the list.
+    pub fn push_front_node(&mut self, mut node: Ref) -> &mut Self {
+        unsafe {
+            node.links_mut().next = self.head;
+            node.links_mut().prev = Link::none();
+
+            let node = Link::from_owning_ref(node);
+
+            match self.head.0 {
+                // The list was empty: the new node is also the tail.
+                None => {
+                    self.tail = node;
+                }
+                // The list already had a head: link it back to the new node.
+                Some(head) => {
+                    (*head.as_ptr()).links_mut().prev = node;
+                }
+            }
+
+            self.head = node;
+            self.len += 1;
+        };
+        self
+    }
+
+    /// Push a node to the tail of the list.
+    pub fn push_back_node(&mut self, mut node: Ref) -> &mut Self {
+        unsafe {
+            node.links_mut().prev = self.tail;
+            node.links_mut().next = Link::none();
+
+            let node = Link::from_owning_ref(node);
+
+            match self.tail.0 {
+                // The list was empty: the new node is also the head.
+                None => {
+                    self.head = node;
+                }
+                // The list already had a tail: link it forward to the new node.
+                Some(tail) => {
+                    (*tail.as_ptr()).links_mut().next = node;
+                }
+            }
+
+            self.tail = node;
+            self.len += 1;
+        };
+        self
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: Linked,
+    Ref: OwningRef<Node>,
+{
+    /// Pop a node from the front of the list.
+    pub fn pop_front_node(&mut self) -> Option<Ref> {
+        unsafe {
+            self.head.as_ptr().map(|node| {
+                self.head = (*node).links_mut().next;
+
+                match self.head.as_mut() {
+                    // The list is now empty: clear the tail too.
+                    None => {
+                        self.tail = Link::none();
+                    }
+                    // The new head has no `prev` node.
+                    Some(head) => {
+                        head.links_mut().prev = Link::none();
+                    }
+                }
+
+                self.len -= 1;
+                Ref::from_ptr(node as *const Node)
+            })
+        }
+    }
+
+    /// Pop a node from the back of the list.
+    pub fn pop_back_node(&mut self) -> Option<Ref> {
+        unsafe {
+            self.tail.as_ptr().map(|node| {
+                self.tail = (*node).links_mut().prev;
+
+                match self.tail.as_mut() {
+                    // The list is now empty: clear the head too.
+                    None => {
+                        self.head = Link::none();
+                    }
+                    // The new tail has no `next` node.
+                    Some(tail) => {
+                        tail.links_mut().next = Link::none();
+                    }
+                }
+
+                self.len -= 1;
+                Ref::from_ptr(node as *const Node)
+            })
+        }
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: Linked,
+{
+    /// Splits the list into two at the given index.
+    ///
+    /// Returns a newly allocated `List` containing the elements `[at, len)`.
+    /// After the call, `self` contains only the elements `[0, at)`.
+    ///
+    /// This runs in `O(min(at, len - at))`, walking from whichever end is
+    /// closer to the split point, and severs the `Links` between the two
+    /// halves without touching any other node.
+    ///
+    /// # Panics
+    /// Panics if `at > self.len()`.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let len = self.len;
+        assert!(
+            at <= len,
+            "cannot split off at index {} of a list of length {}",
+            at,
+            len
+        );
+
+        if at == 0 {
+            return mem::replace(self, List::new());
+        }
+        if at == len {
+            return List::new();
+        }
+
+        // Walk to the node that will become the head of the second half,
+        // approaching from whichever end is closer.
+        let split = if at <= len - at {
+            let mut node = self.head;
+            for _ in 0..at {
+                unsafe {
+                    let ptr = node.as_ptr().expect("walked off the front of the list");
+                    node = (*ptr).links().next;
+                }
+            }
+            node
+        } else {
+            let mut node = self.tail;
+            for _ in 0..(len - at - 1) {
+                unsafe {
+                    let ptr = node.as_ptr().expect("walked off the back of the list");
+                    node = (*ptr).links().prev;
+                }
+            }
+            node
+        };
+
+        unsafe {
+            let split_ptr = split.as_ptr().expect("split point is within the list");
+            let before = (*split_ptr).links().prev;
+            (*split_ptr).links_mut().prev = Link::none();
+
+            let before_ptr = before.as_ptr().expect("at > 0, so a node precedes the split point");
+            (*before_ptr).links_mut().next = Link::none();
+
+            let mut second_half = List::new();
+            second_half.head = split;
+            second_half.tail = self.tail;
+            second_half.len = len - at;
+
+            self.tail = before;
+            self.len = at;
+
+            second_half
+        }
+    }
+}
+
+#[cfg(any(feature = "validate", debug_assertions))]
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: Linked,
+{
+    /// Walks the list from `head` to `tail`, panicking if its internal
+    /// `head`/`tail`/`len` bookkeeping and each node's [`Links`] are not
+    /// mutually consistent.
+    ///
+    /// This is meant for use in tests and assertions, particularly around
+    /// code that backs the list with an [`UnsafeRef`], where the compiler
+    /// has no way to enforce aliasing on its own.
+    ///
+    /// [`Links`]: struct.Links.html
+    /// [`UnsafeRef`]: ../struct.UnsafeRef.html
+    pub fn check_links(&self) {
+        if self.len == 0 {
+            assert!(self.head.0.is_none(), "empty list must have no head");
+            assert!(self.tail.0.is_none(), "empty list must have no tail");
+            return;
+        }
+
+        assert!(self.head.0.is_some(), "non-empty list must have a head");
+        assert!(self.tail.0.is_some(), "non-empty list must have a tail");
+
+        unsafe {
+            let head_ptr = self.head.as_ptr().expect("checked above");
+            assert!(
+                (*head_ptr).links().prev.0.is_none(),
+                "head node must have no `prev`"
+            );
+
+            let mut node = self.head;
+            let mut prev = Link::none();
+            let mut count = 0;
+
+            while let Some(ptr) = node.as_ptr() {
+                assert!(
+                    (*ptr).links().prev.as_ptr() == prev.as_ptr(),
+                    "node {} did not point back at its predecessor",
+                    count
+                );
+                prev = node;
+                node = (*ptr).links().next;
+                count += 1;
+            }
+
+            assert_eq!(
+                count, self.len,
+                "forward walk visited {} nodes, but len is {}",
+                count, self.len
+            );
+
+            let prev_ptr = prev.as_ptr().expect("list is non-empty");
+            assert_eq!(
+                Some(prev_ptr),
+                self.tail.as_ptr(),
+                "last node visited was not `tail`"
+            );
+            assert!(
+                (*prev_ptr).links().next.0.is_none(),
+                "tail node must have no `next`"
+            );
+        }
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: Linked,
+{
+    /// Moves all of `other`'s nodes onto the back of `self`, in O(1),
+    /// leaving `other` empty.
+    ///
+    /// If `self` is empty, this is equivalent to swapping the two lists.
+    pub fn append(&mut self, other: &mut List<T, Node, Ref>) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            mem::swap(self, other);
+            return;
+        }
+
+        unsafe {
+            let self_tail = self.tail.as_ptr().expect("self is non-empty");
+            let other_head = other.head.as_ptr().expect("other is non-empty");
+
+            (*self_tail).links_mut().next = other.head;
+            (*other_head).links_mut().prev = self.tail;
+        }
+
+        self.tail = other.tail;
+        self.len += other.len;
+
+        other.head = Link::none();
+        other.tail = Link::none();
+        other.len = 0;
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: AsRef<T>,
+{
+    /// Borrows the first item of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn front(&self) -> Option<&T> {
+        self.head().map(Node::as_ref)
+    }
+
+    /// Borrows the last item of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.tail().map(Node::as_ref)
+    }
+}
+
+impl<T, Node, Ref> List<T, Node, Ref>
+where
+    Node: AsMut<T>,
+{
+    /// Mutably borrows the first element of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&mut T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.head_mut().map(Node::as_mut)
+    }
+
+    /// Mutably borrows the last element of the list as an `Option`.
+    ///
+    /// # Returns
+    ///   - `Some(&mut T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail_mut().map(Node::as_mut)
+    }
+}
+
+// ===== impl Extend / FromIterator =====
+
+impl<T, Node, Ref> Extend<Ref> for List<T, Node, Ref>
+where
+    Node: Linked,
+    Ref: OwningRef<Node> + DerefMut,
+{
+    /// Extends the list by pushing each node in `iter` onto the back, in
+    /// order.
+    fn extend<I: IntoIterator<Item = Ref>>(&mut self, iter: I) {
+        for node in iter {
+            self.push_back_node(node);
+        }
+    }
+}
+
+impl<T, Node, Ref> FromIterator<Ref> for List<T, Node, Ref>
+where
+    Node: Linked,
+    Ref: OwningRef<Node> + DerefMut,
+{
+    /// Builds a list by pushing each node in `iter` onto the back, in
+    /// order.
+    fn from_iter<I: IntoIterator<Item = Ref>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    not(any(feature = "std", test))
+))]
+use alloc::boxed::Box;
+#[cfg(any(feature = "std", test))]
+use std::boxed::Box;
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> List<T, Node, Box<Node>>
+where
+    Node: From<T>,
+    Node: Linked,
+{
+    /// Push an item to the front of the list.
+    #[inline]
+    pub fn push_front(&mut self, item: T) -> &mut Self {
+        self.push_front_node(Box::new(Node::from(item)))
+    }
+
+    /// Push an item to the back of the list.
+    #[inline]
+    pub fn push_back(&mut self, item: T) -> &mut Self {
+        self.push_back_node(Box::new(Node::from(item)))
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> List<T, Node, Box<Node>>
+where
+    Node: Linked,
+    Node: Into<T>,
+{
+    /// Pop an item from the front of the list.
+    #[inline]
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.pop_front_node().map(|b| (*b).into())
+    }
+
+    /// Pop an item from the back of the list.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.pop_back_node().map(|b| (*b).into())
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> Extend<T> for List<T, Node, Box<Node>>
+where
+    Node: From<T>,
+    Node: Linked,
+{
+    /// Extends the list by boxing each item in `iter` and pushing it onto
+    /// the back, in order.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> FromIterator<T> for List<T, Node, Box<Node>>
+where
+    Node: From<T>,
+    Node: Linked,
+{
+    /// Builds a list by boxing each item in `iter` and pushing it onto the
+    /// back, in order.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
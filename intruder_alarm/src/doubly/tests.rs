@@ -836,4 +836,419 @@ mod unsafe_ref {
             assert_eq!(nlist.pop_front_node().unwrap().number, i);
         }
     }
+}
+
+mod cursor {
+    use super::*;
+    use std::boxed::Box;
+
+    type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+    fn list_from(items: &[usize]) -> NumberedList {
+        let mut list = NumberedList::new();
+        for &i in items {
+            list.push_back(i);
+        }
+        list
+    }
+
+    #[test]
+    fn move_next_and_move_prev_wrap_through_the_ghost_position() {
+        let mut list = list_from(&[0, 1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.move_next();
+        assert_eq!(cursor.current(), None, "cursor should be at the ghost position");
+
+        cursor.move_next();
+        assert_eq!(
+            cursor.current(),
+            Some(&mut 0),
+            "moving past the ghost position should wrap to the front"
+        );
+    }
+
+    #[test]
+    fn peek_next_does_not_wrap_past_the_last_node() {
+        let mut list = list_from(&[0, 1]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(
+            cursor.peek_next(),
+            None,
+            "peeking past the last node must not wrap to the head"
+        );
+        assert_eq!(cursor.peek_prev(), Some(&0));
+    }
+
+    #[test]
+    fn peek_prev_does_not_wrap_past_the_first_node() {
+        let mut list = list_from(&[0, 1]);
+        let cursor = list.cursor_front_mut();
+
+        assert_eq!(
+            cursor.peek_prev(),
+            None,
+            "peeking before the first node must not wrap to the tail"
+        );
+        assert_eq!(cursor.peek_next(), Some(&1));
+    }
+
+    #[test]
+    fn peek_next_and_peek_prev_at_the_ghost_position() {
+        let mut list = list_from(&[0, 1]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&0));
+        assert_eq!(cursor.peek_prev(), Some(&1));
+    }
+
+    #[test]
+    fn remove_current_advances_to_the_next_node() {
+        let mut list = list_from(&[0, 1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let removed = cursor.remove_current().unwrap();
+        assert_eq!(removed.number, 1);
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn insert_after_node_at_the_ghost_position_pushes_to_the_front() {
+        let mut list = list_from(&[0]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        cursor.insert_after_node(Box::new(NumberedNode::new(1)));
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn insert_before_node_splices_in_before_current() {
+        let mut list = list_from(&[0, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.insert_before_node(Box::new(NumberedNode::new(1)));
+
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn splice_after_moves_all_of_the_other_lists_nodes() {
+        let mut list = list_from(&[0, 3]);
+        let mut other = list_from(&[1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.splice_after(&mut other);
+
+        assert!(other.is_empty());
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        list.check_links();
+    }
+
+    #[test]
+    fn splice_before_moves_all_of_the_other_lists_nodes() {
+        let mut list = list_from(&[0, 3]);
+        let mut other = list_from(&[1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.splice_before(&mut other);
+
+        assert!(other.is_empty());
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        list.check_links();
+    }
+
+    #[test]
+    fn splice_before_at_the_ghost_position_splices_onto_the_back() {
+        let mut list = list_from(&[0, 1]);
+        let mut other = list_from(&[2, 3]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        cursor.splice_before(&mut other);
+
+        assert!(other.is_empty());
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        list.check_links();
+    }
+
+    #[test]
+    fn splice_after_leaves_the_cursor_index_unchanged() {
+        let mut list = list_from(&[0, 3]);
+        let mut other = list_from(&[1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.splice_after(&mut other);
+        cursor.move_next();
+
+        assert_eq!(
+            cursor.index(),
+            Some(1),
+            "the node after the splice point should still be at index 1"
+        );
+    }
+
+    #[test]
+    fn splice_before_advances_index_by_the_spliced_in_length() {
+        let mut list = list_from(&[0, 3]);
+        let mut other = list_from(&[1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.splice_before(&mut other);
+
+        assert_eq!(
+            cursor.index(),
+            Some(3),
+            "splicing 2 nodes in before index 1 should push the cursor to index 3"
+        );
+    }
+}
+
+mod split_off {
+    use super::*;
+    use std::boxed::Box;
+
+    type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+    fn list_from(items: &[usize]) -> NumberedList {
+        let mut list = NumberedList::new();
+        for &i in items {
+            list.push_back(i);
+        }
+        list
+    }
+
+    #[test]
+    fn split_off_at_zero_moves_everything_to_the_returned_half() {
+        let mut list = list_from(&[0, 1, 2]);
+
+        let second_half = list.split_off(0);
+
+        assert!(list.is_empty());
+        assert_eq!(list.head(), None);
+        assert_eq!(list.tail(), None);
+        list.check_links();
+
+        assert_eq!(second_half.len(), 3);
+        assert_eq!(second_half.front(), Some(&0));
+        let items: Vec<usize> = second_half.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+        second_half.check_links();
+    }
+
+    #[test]
+    fn split_off_at_len_returns_an_empty_list() {
+        let mut list = list_from(&[0, 1, 2]);
+
+        let second_half = list.split_off(3);
+
+        assert_eq!(list.len(), 3);
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+        list.check_links();
+
+        assert!(second_half.is_empty());
+        second_half.check_links();
+    }
+
+    #[test]
+    fn split_off_in_the_middle_divides_the_list_in_two() {
+        let mut list = list_from(&[0, 1, 2, 3]);
+
+        let second_half = list.split_off(2);
+
+        assert_eq!(list.len(), 2);
+        let first_items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(first_items, vec![0, 1]);
+        assert_eq!(list.front(), Some(&0));
+        list.check_links();
+
+        assert_eq!(second_half.len(), 2);
+        let second_items: Vec<usize> = second_half.iter().cloned().collect();
+        assert_eq!(second_items, vec![2, 3]);
+        assert_eq!(second_half.front(), Some(&2));
+        second_half.check_links();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot split off at index 4 of a list of length 3")]
+    fn split_off_past_the_end_panics() {
+        let mut list = list_from(&[0, 1, 2]);
+        list.split_off(4);
+    }
+}
+
+mod iter {
+    use super::*;
+    use std::boxed::Box;
+
+    type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+    fn list_from(items: &[usize]) -> NumberedList {
+        let mut list = NumberedList::new();
+        for &i in items {
+            list.push_back(i);
+        }
+        list
+    }
+
+    #[test]
+    fn iter_visits_items_front_to_back() {
+        let list = list_from(&[0, 1, 2]);
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn iter_mut_allows_updating_items_in_place() {
+        let mut list = list_from(&[0, 1, 2]);
+        for n in list.iter_mut() {
+            *n += 10;
+        }
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn drain_removes_every_node_and_leaves_the_list_empty() {
+        let mut list = list_from(&[0, 1, 2]);
+
+        let drained: Vec<usize> = list.drain().map(|node| node.number).collect();
+
+        assert_eq!(drained, vec![0, 1, 2]);
+        assert!(list.is_empty());
+        list.check_links();
+    }
+}
+
+mod append {
+    use super::*;
+    use std::boxed::Box;
+
+    type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+    #[test]
+    fn append_concatenates_the_lists_in_order() {
+        let mut a = NumberedList::new();
+        a.push_back(0);
+        a.push_back(1);
+
+        let mut b = NumberedList::new();
+        b.push_back(2);
+        b.push_back(3);
+
+        a.append(&mut b);
+
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 4);
+        let items: Vec<usize> = a.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2, 3]);
+        a.check_links();
+    }
+
+    #[test]
+    fn append_to_an_empty_list_swaps_in_the_other_list() {
+        let mut a = NumberedList::new();
+        let mut b = NumberedList::new();
+        b.push_back(0);
+        b.push_back(1);
+
+        a.append(&mut b);
+
+        assert!(b.is_empty());
+        assert_eq!(a.len(), 2);
+        a.check_links();
+    }
+
+    #[test]
+    fn append_an_empty_list_is_a_no_op() {
+        let mut a = NumberedList::new();
+        a.push_back(0);
+        let mut b = NumberedList::new();
+
+        a.append(&mut b);
+
+        assert_eq!(a.len(), 1);
+        a.check_links();
+    }
+}
+
+mod drain_filter {
+    use super::*;
+    use std::boxed::Box;
+
+    type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+    fn list_from(items: &[usize]) -> NumberedList {
+        let mut list = NumberedList::new();
+        for &i in items {
+            list.push_back(i);
+        }
+        list
+    }
+
+    #[test]
+    fn drain_filter_removes_matching_items_in_order() {
+        let mut list = list_from(&[0, 1, 2, 3, 4, 5]);
+
+        let removed: Vec<usize> = list
+            .drain_filter(|n| n % 2 == 0)
+            .map(|node| node.number)
+            .collect();
+
+        assert_eq!(removed, vec![0, 2, 4]);
+        let remaining: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(remaining, vec![1, 3, 5]);
+        list.check_links();
+    }
+
+    #[test]
+    fn dropping_a_partially_consumed_drain_filter_still_removes_the_rest() {
+        let mut list = list_from(&[0, 1, 2, 3, 4, 5]);
+
+        {
+            let mut iter = list.drain_filter(|n| n % 2 == 0);
+            assert_eq!(iter.next().unwrap().number, 0);
+            // Dropping here, with matching items still unvisited, must
+            // finish the walk and remove them anyway.
+        }
+
+        let remaining: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(remaining, vec![1, 3, 5]);
+        list.check_links();
+    }
+
+    #[test]
+    fn retain_keeps_only_the_items_the_predicate_accepts() {
+        let mut list = list_from(&[0, 1, 2, 3, 4, 5]);
+
+        list.retain(|n| n % 2 != 0);
+
+        let remaining: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(remaining, vec![1, 3, 5]);
+        list.check_links();
+    }
 }
\ No newline at end of file
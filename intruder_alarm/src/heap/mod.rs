@@ -0,0 +1,394 @@
+//! An intrusive max-heap / priority queue.
+//!
+//! Like [`singly::List`] and [`doubly::List`], this is a pointer-linked
+//! collection: rather than storing elements in a `Vec` and indexing into
+//! it, each node stores [`Links`] to its parent and two children, and the
+//! heap itself only stores a [`Link`] to the root plus a node count. This
+//! lets OS code keep, e.g., timer deadlines or scheduler priorities
+//! ordered without heap-allocating a backing array.
+//!
+//! The node at array index `i` in a conventional binary-heap-in-a-`Vec`
+//! has children at `2i` and `2i + 1`; here, the same complete-binary-tree
+//! shape is found by walking from the root, using the bits of a node's
+//! 1-indexed position (from most to least significant, skipping the
+//! leading bit that just selects the root) to choose left or right at each
+//! step. This is what [`Heap::node_at`] does, and it is what lets
+//! `push_node`/`pop_node` find the tree's "last" position in `O(log n)`
+//! without storing it explicitly.
+//!
+//! [`singly::List`]: ../singly/struct.List.html
+//! [`doubly::List`]: ../doubly/struct.List.html
+use super::{Link, OwningRef};
+use core::marker::PhantomData;
+use core::mem;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(test)]
+mod tests;
+
+//-----------------------------------------------------------------------------
+// Public API types
+//-----------------------------------------------------------------------------
+
+/// A node's parent and child [`Link`]s.
+///
+/// [`Link`]: ../struct.Link.html
+#[derive(Debug)]
+pub struct Links<T> {
+    parent: Link<T>,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Links {
+            parent: Link::none(),
+            left: Link::none(),
+            right: Link::none(),
+        }
+    }
+}
+
+/// Trait that must be implemented in order to be a member of a [`Heap`].
+///
+/// [`Heap`]: struct.Heap.html
+pub trait HeapLinked: Sized {
+    /// Borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links(&self) -> &Links<Self>;
+
+    /// Mutably borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links_mut(&mut self) -> &mut Links<Self>;
+}
+
+/// An intrusive max-heap.
+///
+/// # Type parameters
+/// - `T`: the type of the items stored by each `Node`, ordered by [`Ord`]
+/// - `Node`: the type of nodes in the heap
+/// - `Ref`: the type of [`OwningRef`] that owns each `Node`.
+///
+/// [`OwningRef`]: ../trait.OwningRef.html
+pub struct Heap<T, Node, Ref> {
+    root: Link<Node>,
+    len: usize,
+    _elem_ty: PhantomData<T>,
+    _ref_ty: PhantomData<Ref>,
+}
+
+/// An RAII guard granting mutable access to the item at the top of a
+/// [`Heap`], which re-establishes the heap property by sifting the item
+/// back into place when dropped.
+///
+/// Created by [`Heap::peek_mut`].
+///
+/// [`Heap`]: struct.Heap.html
+/// [`Heap::peek_mut`]: struct.Heap.html#method.peek_mut
+pub struct PeekMut<'a, T: 'a, Node: 'a, Ref: 'a>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    heap: &'a mut Heap<T, Node, Ref>,
+}
+
+//-----------------------------------------------------------------------------
+// Implementations
+//-----------------------------------------------------------------------------
+
+// ===== impl Heap =====
+
+impl<T, Node, Ref> Heap<T, Node, Ref> {
+    /// Create a new, empty `Heap`.
+    pub const fn new() -> Self {
+        Heap {
+            root: Link::none(),
+            len: 0,
+            _elem_ty: PhantomData,
+            _ref_ty: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the heap.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the heap is empty, false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<T, Node, Ref> Heap<T, Node, Ref>
+where
+    Node: HeapLinked,
+{
+    /// Finds the node at 1-indexed complete-binary-tree position `index`,
+    /// walking down from the root. Returns `Link::none()` if `index` is 0
+    /// or falls outside the current tree.
+    fn node_at(&self, index: usize) -> Link<Node> {
+        if index == 0 {
+            return Link::none();
+        }
+
+        let bits = mem::size_of::<usize>() * 8;
+        let highest_bit = bits - 1 - index.leading_zeros() as usize;
+        // Skip the leading bit, which just selects the root.
+        let mut mask = if highest_bit == 0 {
+            0
+        } else {
+            1usize << (highest_bit - 1)
+        };
+        let mut current = self.root;
+
+        while mask != 0 {
+            current = match current.as_ptr() {
+                None => return Link::none(),
+                Some(ptr) => unsafe {
+                    if index & mask != 0 {
+                        (*ptr).links().right
+                    } else {
+                        (*ptr).links().left
+                    }
+                },
+            };
+            mask >>= 1;
+        }
+
+        current
+    }
+}
+
+impl<T, Node, Ref> Heap<T, Node, Ref>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    /// Pushes a node into the heap, in `O(log n)`.
+    pub fn push_node(&mut self, node: Ref) {
+        let index = self.len + 1;
+        let parent = self.node_at(index >> 1);
+        let child = Link::from_owning_ref(node);
+
+        unsafe {
+            let child_ptr = child.as_ptr().expect("just created from an owning ref");
+            (*child_ptr).links_mut().parent = parent;
+
+            match parent.as_ptr() {
+                // `index == 1`: this node is the new root.
+                None => self.root = child,
+                Some(parent_ptr) => {
+                    if index & 1 == 0 {
+                        (*parent_ptr).links_mut().left = child;
+                    } else {
+                        (*parent_ptr).links_mut().right = child;
+                    }
+                }
+            }
+        }
+
+        self.len += 1;
+        self.sift_up(child);
+    }
+
+    /// Pops the greatest node off of the heap, in `O(log n)`.
+    pub fn pop_node(&mut self) -> Option<Ref> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let last = self.node_at(self.len);
+        unsafe {
+            let last_ptr = last.as_ptr().expect("len > 0, so the last position exists");
+            let root_ptr = self.root.as_ptr().expect("len > 0, so a root exists");
+
+            // Swap the root's payload down into the last position, so
+            // that removing the last node removes the greatest value.
+            if root_ptr != last_ptr {
+                mem::swap((*root_ptr).as_mut(), (*last_ptr).as_mut());
+            }
+
+            // Detach `last` from the tree.
+            match (*last_ptr).links().parent.as_ptr() {
+                Some(parent_ptr) => {
+                    if (*parent_ptr).links().left.as_ptr() == Some(last_ptr) {
+                        (*parent_ptr).links_mut().left = Link::none();
+                    } else {
+                        (*parent_ptr).links_mut().right = Link::none();
+                    }
+                }
+                None => self.root = Link::none(),
+            }
+            (*last_ptr).links_mut().parent = Link::none();
+
+            self.len -= 1;
+            self.sift_down_from_root();
+
+            Some(Ref::from_ptr(last_ptr as *const Node))
+        }
+    }
+
+    /// Sifts the node at `current` up towards the root by repeatedly
+    /// swapping its payload with its parent's while it outranks it.
+    fn sift_up(&mut self, mut current: Link<Node>) {
+        unsafe {
+            while let Some(ptr) = current.as_ptr() {
+                let parent = match (*ptr).links().parent.as_ptr() {
+                    Some(parent_ptr) => parent_ptr,
+                    None => break,
+                };
+
+                if (*ptr).as_ref() <= (*parent).as_ref() {
+                    break;
+                }
+
+                mem::swap((*ptr).as_mut(), (*parent).as_mut());
+                current = (*ptr).links().parent;
+            }
+        }
+    }
+
+    /// Sifts the root's payload down towards the larger of its children,
+    /// repeatedly, until the heap property is restored.
+    fn sift_down_from_root(&mut self) {
+        unsafe {
+            let mut current = match self.root.as_ptr() {
+                Some(ptr) => ptr,
+                None => return,
+            };
+
+            loop {
+                let left = (*current).links().left.as_ptr();
+                let right = (*current).links().right.as_ptr();
+
+                let larger = match (left, right) {
+                    (None, None) => break,
+                    (Some(l), None) => l,
+                    (None, Some(r)) => r,
+                    (Some(l), Some(r)) => {
+                        if (*r).as_ref() > (*l).as_ref() {
+                            r
+                        } else {
+                            l
+                        }
+                    }
+                };
+
+                if (*larger).as_ref() > (*current).as_ref() {
+                    mem::swap((*current).as_mut(), (*larger).as_mut());
+                    current = larger;
+                } else {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+impl<T, Node, Ref> Heap<T, Node, Ref>
+where
+    Node: HeapLinked + AsRef<T>,
+{
+    /// Borrows the greatest item in the heap, or `None` if it is empty.
+    #[inline]
+    pub fn peek(&self) -> Option<&T> {
+        self.root.as_ref().map(Node::as_ref)
+    }
+}
+
+impl<T, Node, Ref> Heap<T, Node, Ref>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    /// Returns an RAII guard granting mutable access to the greatest item
+    /// in the heap, which re-sifts it into place on drop, or `None` if
+    /// the heap is empty.
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, Node, Ref>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut { heap: self })
+        }
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+use std::boxed::Box;
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> Heap<T, Node, Box<Node>>
+where
+    T: Ord,
+    Node: From<T> + HeapLinked + AsRef<T> + AsMut<T>,
+{
+    /// Pushes an item into the heap, in `O(log n)`.
+    #[inline]
+    pub fn push(&mut self, item: T) {
+        self.push_node(Box::new(Node::from(item)));
+    }
+}
+
+#[cfg(any(feature = "alloc", feature = "std", test))]
+impl<T, Node> Heap<T, Node, Box<Node>>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T> + Into<T>,
+{
+    /// Pops the greatest item off of the heap, in `O(log n)`.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        self.pop_node().map(|b| (*b).into())
+    }
+}
+
+// ===== impl PeekMut =====
+
+impl<'a, T, Node, Ref> Deref for PeekMut<'a, T, Node, Ref>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.heap.peek().expect("PeekMut always points at a node")
+    }
+}
+
+impl<'a, T, Node, Ref> DerefMut for PeekMut<'a, T, Node, Ref>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    fn deref_mut(&mut self) -> &mut T {
+        self.heap
+            .root
+            .as_mut()
+            .expect("PeekMut always points at a node")
+            .as_mut()
+    }
+}
+
+impl<'a, T, Node, Ref> Drop for PeekMut<'a, T, Node, Ref>
+where
+    T: Ord,
+    Node: HeapLinked + AsRef<T> + AsMut<T>,
+    Ref: OwningRef<Node>,
+{
+    fn drop(&mut self) {
+        self.heap.sift_down_from_root();
+    }
+}
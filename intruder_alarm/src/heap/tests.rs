@@ -0,0 +1,112 @@
+use super::*;
+use std::boxed::Box;
+
+#[derive(Default, Debug)]
+pub struct NumberedNode {
+    pub number: usize,
+    links: Links<NumberedNode>,
+}
+
+impl NumberedNode {
+    pub fn new(number: usize) -> Self {
+        NumberedNode {
+            number,
+            ..Default::default()
+        }
+    }
+}
+
+impl HeapLinked for NumberedNode {
+    fn links(&self) -> &Links<Self> {
+        &self.links
+    }
+
+    fn links_mut(&mut self) -> &mut Links<Self> {
+        &mut self.links
+    }
+}
+
+impl AsRef<usize> for NumberedNode {
+    fn as_ref(&self) -> &usize {
+        &self.number
+    }
+}
+
+impl AsMut<usize> for NumberedNode {
+    fn as_mut(&mut self) -> &mut usize {
+        &mut self.number
+    }
+}
+
+impl From<usize> for NumberedNode {
+    fn from(u: usize) -> NumberedNode {
+        NumberedNode::new(u)
+    }
+}
+
+impl Into<usize> for NumberedNode {
+    fn into(self) -> usize {
+        self.number
+    }
+}
+
+type NumberedHeap = Heap<usize, NumberedNode, Box<NumberedNode>>;
+
+#[test]
+fn empty_heap_has_no_peek() {
+    let heap: NumberedHeap = Heap::new();
+    assert!(heap.is_empty());
+    assert_eq!(heap.len(), 0);
+    assert_eq!(heap.peek(), None);
+}
+
+#[test]
+fn peek_returns_the_greatest_item() {
+    let mut heap = NumberedHeap::new();
+    heap.push(1);
+    heap.push(5);
+    heap.push(3);
+
+    assert_eq!(heap.peek(), Some(&5));
+    assert_eq!(heap.len(), 3);
+}
+
+#[test]
+fn pop_returns_items_in_descending_order() {
+    let mut heap = NumberedHeap::new();
+    for n in vec![3, 1, 4, 1, 5, 9, 2, 6] {
+        heap.push(n);
+    }
+
+    let mut popped = Vec::new();
+    while let Some(n) = heap.pop() {
+        popped.push(n);
+    }
+
+    let mut expected = vec![3, 1, 4, 1, 5, 9, 2, 6];
+    expected.sort_unstable_by(|a, b| b.cmp(a));
+    assert_eq!(popped, expected);
+    assert!(heap.is_empty());
+}
+
+#[test]
+fn pop_on_an_empty_heap_returns_none() {
+    let mut heap = NumberedHeap::new();
+    assert_eq!(heap.pop(), None);
+}
+
+#[test]
+fn peek_mut_re_sifts_on_drop() {
+    let mut heap = NumberedHeap::new();
+    heap.push(5);
+    heap.push(3);
+    heap.push(1);
+
+    {
+        let mut top = heap.peek_mut().unwrap();
+        *top = 0;
+    }
+
+    assert_eq!(heap.peek(), Some(&3));
+    assert_eq!(heap.len(), 3);
+}
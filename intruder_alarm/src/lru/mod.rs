@@ -0,0 +1,162 @@
+//! An intrusive LRU (least-recently-used) cache, built on top of
+//! [`UnsafeList`].
+//!
+//! [`UnsafeList`] already provides the two primitives an LRU needs in
+//! `O(1)`: removing an arbitrary node from the middle of the list (to
+//! promote a hit), and popping the tail (to evict the coldest entry). This
+//! module layers a [`HashMap`] for key lookup on top of that list to get a
+//! full cache: the list tracks recency order, head-to-tail, and the map
+//! gives `O(1)` access to the node for a given key.
+//!
+//! [`UnsafeList`]: ../unsafe_list/struct.UnsafeList.html
+//! [`HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+use super::unsafe_list::{Links, UnsafeList, UnsafeListLinked};
+use super::UnsafeRef;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::mem;
+
+#[cfg(test)]
+mod tests;
+
+struct Node<K, V> {
+    key: K,
+    value: V,
+    links: Links<Node<K, V>>,
+}
+
+impl<K, V> UnsafeListLinked for Node<K, V> {
+    fn links(&self) -> &Links<Self> {
+        &self.links
+    }
+
+    fn links_mut(&mut self) -> &mut Links<Self> {
+        &mut self.links
+    }
+}
+
+/// A data source that can populate an [`LruCache`]'s misses.
+///
+/// [`LruCache`]: struct.LruCache.html
+pub trait Cacher<K, V> {
+    /// Fetches the value for `key` from the underlying source, or returns
+    /// `None` if there is no value for that key.
+    fn fetch(&mut self, key: K) -> Option<V>;
+}
+
+/// A fixed-capacity cache that evicts its least-recently-used entry once
+/// a new insertion would exceed that capacity.
+pub struct LruCache<K, V> {
+    capacity: usize,
+    list: UnsafeList<Node<K, V>>,
+    map: HashMap<K, UnsafeRef<Node<K, V>>>,
+}
+
+impl<K, V> LruCache<K, V>
+where
+    K: Hash + Eq,
+{
+    /// Creates a new, empty cache that holds at most `capacity` entries.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is 0.
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "an LruCache must have a capacity of at least 1");
+        LruCache {
+            capacity,
+            list: UnsafeList::new(),
+            map: HashMap::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of entries currently in the cache.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    /// Returns true if the cache holds no entries.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns the maximum number of entries this cache will hold.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns a reference to the value for `key`, promoting it to
+    /// most-recently-used, or `None` if it is not present.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let node = *self.map.get(key)?;
+        unsafe {
+            self.list.remove(node);
+            self.list.push_front_node(node);
+        }
+        self.list.head().map(|node| &node.value)
+    }
+
+    /// Inserts `value` for `key`, promoting it to most-recently-used.
+    ///
+    /// If `key` was already present, its old value is returned. Otherwise,
+    /// if the cache is now over capacity, the least-recently-used entry is
+    /// evicted and its key removed from the map.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        K: Clone,
+    {
+        if let Some(&node) = self.map.get(&key) {
+            unsafe {
+                self.list.remove(node);
+                self.list.push_front_node(node);
+                let mut node = node;
+                return Some(mem::replace(&mut node.value, value));
+            }
+        }
+
+        let node = UnsafeRef::boxed(Node {
+            key: key.clone(),
+            value,
+            links: Links::default(),
+        });
+        self.map.insert(key, node);
+        unsafe {
+            self.list.push_front_node(node);
+        }
+
+        if self.list.len() > self.capacity {
+            if let Some(evicted) = unsafe { self.list.pop_back_node() } {
+                self.map.remove(&evicted.key);
+                unsafe {
+                    drop(UnsafeRef::into_box(evicted));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the value for `key`, promoting it to most-recently-used if
+    /// it was already present, or fetching and inserting it from `cacher`
+    /// if it was not.
+    pub fn get_or_fetch<C>(&mut self, key: K, cacher: &mut C) -> Option<V>
+    where
+        K: Clone,
+        V: Clone,
+        C: Cacher<K, V>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Some(value.clone());
+        }
+        let value = cacher.fetch(key.clone())?;
+        self.insert(key, value.clone());
+        Some(value)
+    }
+}
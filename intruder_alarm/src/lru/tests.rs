@@ -0,0 +1,71 @@
+use super::*;
+
+#[test]
+#[should_panic(expected = "capacity of at least 1")]
+fn new_panics_on_zero_capacity() {
+    let _: LruCache<usize, usize> = LruCache::new(0);
+}
+
+#[test]
+fn insert_and_get_round_trip() {
+    let mut cache = LruCache::new(2);
+    assert_eq!(cache.insert(1, "a"), None);
+    assert_eq!(cache.get(&1), Some(&"a"));
+}
+
+#[test]
+fn insert_over_an_existing_key_returns_the_old_value() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, "a");
+    assert_eq!(cache.insert(1, "b"), Some("a"));
+    assert_eq!(cache.get(&1), Some(&"b"));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn inserting_past_capacity_evicts_the_least_recently_used_entry() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, "a");
+    cache.insert(2, "b");
+    cache.insert(3, "c");
+
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.get(&1), None, "the coldest entry should have been evicted");
+    assert_eq!(cache.get(&2), Some(&"b"));
+    assert_eq!(cache.get(&3), Some(&"c"));
+}
+
+#[test]
+fn get_promotes_an_entry_to_most_recently_used() {
+    let mut cache = LruCache::new(2);
+    cache.insert(1, "a");
+    cache.insert(2, "b");
+
+    // Touch `1` so that `2` becomes the coldest entry.
+    assert_eq!(cache.get(&1), Some(&"a"));
+    cache.insert(3, "c");
+
+    assert_eq!(cache.get(&2), None, "touching 1 should have made 2 the coldest entry");
+    assert_eq!(cache.get(&1), Some(&"a"));
+    assert_eq!(cache.get(&3), Some(&"c"));
+}
+
+struct ConstantCacher(&'static str);
+
+impl Cacher<usize, &'static str> for ConstantCacher {
+    fn fetch(&mut self, _key: usize) -> Option<&'static str> {
+        Some(self.0)
+    }
+}
+
+#[test]
+fn get_or_fetch_fetches_and_caches_a_miss() {
+    let mut cache = LruCache::new(2);
+    let mut cacher = ConstantCacher("fetched");
+
+    assert_eq!(cache.get_or_fetch(1, &mut cacher), Some("fetched"));
+    assert_eq!(cache.len(), 1);
+
+    cacher.0 = "should not be seen";
+    assert_eq!(cache.get_or_fetch(1, &mut cacher), Some("fetched"));
+}
@@ -0,0 +1,146 @@
+//! A cursor over a [`List`], allowing in-place traversal, insertion, and
+//! removal.
+//!
+//! [`List`]: ../struct.List.html
+use super::{mark_inserted, GetLinks, Link, List, OwningRef};
+use core::ops::DerefMut;
+
+/// A cursor over a [`List`] that allows inspecting, inserting, and removing
+/// elements at an arbitrary position without walking the list again from
+/// the head.
+///
+/// Created by [`List::cursor_front_mut`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::cursor_front_mut`]: ../struct.List.html#method.cursor_front_mut
+pub struct CursorMut<'a, T: 'a, N: 'a, R: 'a, L: 'a> {
+    list: &'a mut List<T, N, R, L>,
+    current: Link<N>,
+}
+
+impl<T, N, R, L> List<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+{
+    /// Returns a cursor positioned at the front of the list.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N, R, L> {
+        CursorMut {
+            current: self.head,
+            list: self,
+        }
+    }
+}
+
+impl<'a, T, N, R, L> CursorMut<'a, T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+{
+    /// Advances the cursor to the next node in the list.
+    ///
+    /// If the cursor was already past the end of the list, it remains
+    /// there.
+    pub fn move_next(&mut self) {
+        self.current = match self.current.as_ptr() {
+            Some(ptr) => unsafe { *L::get_links(&*ptr) },
+            None => Link::none(),
+        };
+    }
+}
+
+impl<'a, T, N, R, L> CursorMut<'a, T, N, R, L>
+where
+    N: AsMut<T>,
+{
+    /// Returns a mutable reference to the element at the cursor's current
+    /// position, or `None` if the cursor is past the end of the list.
+    pub fn current(&mut self) -> Option<&mut T> {
+        unsafe { self.current.as_mut().map(N::as_mut) }
+    }
+}
+
+impl<'a, T, N, R, L> CursorMut<'a, T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N> + DerefMut,
+{
+    /// Removes the node at the cursor's current position and returns its
+    /// owning [`Ref`], advancing the cursor to the node that followed it.
+    ///
+    /// Returns `None`, without moving the cursor, if it is past the end
+    /// of the list.
+    ///
+    /// [`Ref`]: ../trait.OwningRef.html
+    pub fn remove_current(&mut self) -> Option<R> {
+        let ptr = self.current.as_ptr()?;
+        unsafe {
+            self.current = *L::get_links(&*ptr);
+            self.list.remove_node(&mut *ptr)
+        }
+    }
+
+    /// Inserts `node` immediately after the cursor's current position.
+    ///
+    /// If the cursor is past the end of the list, `node` is pushed onto
+    /// the front of the list instead.
+    ///
+    /// # Errors
+    /// If `node` is already linked into a list (including this one),
+    /// linking it in again would corrupt both lists, so the node is
+    /// rejected and handed back as `Err(node)`.
+    pub fn insert_after(&mut self, mut node: R) -> Result<(), R> {
+        let ptr = match self.current.as_ptr() {
+            Some(ptr) => ptr,
+            None => return self.list.push_front_node(node).map(|_| ()),
+        };
+        if !mark_inserted::<L>(&*node) {
+            return Err(node);
+        }
+        unsafe {
+            let next = *L::get_links(&*ptr);
+            *L::get_links_mut(&mut *node) = next;
+            *L::get_prev_links_mut(&mut *node) = self.current;
+            let node = Link::from_owning_ref(node);
+
+            match next.as_ptr() {
+                Some(next_ptr) => *L::get_prev_links_mut(&mut *next_ptr) = node,
+                None => self.list.tail = node,
+            }
+            *L::get_links_mut(&mut *ptr) = node;
+            self.list.len += 1;
+        }
+        Ok(())
+    }
+
+    /// Inserts `node` immediately before the cursor's current position.
+    ///
+    /// If the cursor is past the end of the list, `node` is pushed onto
+    /// the back of the list instead.
+    ///
+    /// # Errors
+    /// If `node` is already linked into a list (including this one),
+    /// linking it in again would corrupt both lists, so the node is
+    /// rejected and handed back as `Err(node)`.
+    pub fn insert_before(&mut self, mut node: R) -> Result<(), R> {
+        let ptr = match self.current.as_ptr() {
+            Some(ptr) => ptr,
+            None => return self.list.push_back_node(node).map(|_| ()),
+        };
+        if !mark_inserted::<L>(&*node) {
+            return Err(node);
+        }
+        unsafe {
+            let prev = *L::get_prev_links(&*ptr);
+            *L::get_prev_links_mut(&mut *node) = prev;
+            *L::get_links_mut(&mut *node) = self.current;
+            let node = Link::from_owning_ref(node);
+
+            match prev.as_ptr() {
+                Some(prev_ptr) => *L::get_links_mut(&mut *prev_ptr) = node,
+                None => self.list.head = node,
+            }
+            *L::get_prev_links_mut(&mut *ptr) = node;
+            self.list.len += 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+//! Iterators over [`List`]'s elements.
+//!
+//! [`List`]: ../struct.List.html
+use super::{GetLinks, Link, List};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use super::OwningRef;
+
+/// An iterator over references to the items stored in a [`List`].
+///
+/// Created by [`List::iter`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::iter`]: ../struct.List.html#method.iter
+pub struct Iter<'a, T: 'a, N: 'a, L> {
+    head: Option<&'a N>,
+    tail: Option<&'a N>,
+    len: usize,
+    _elem_ty: PhantomData<&'a T>,
+    _link_ty: PhantomData<L>,
+}
+
+/// An iterator over mutable references to the items stored in a [`List`].
+///
+/// Created by [`List::iter_mut`].
+///
+/// [`List`]: ../struct.List.html
+/// [`List::iter_mut`]: ../struct.List.html#method.iter_mut
+pub struct IterMut<'a, T: 'a, N: 'a, L> {
+    head: Link<N>,
+    tail: Link<N>,
+    len: usize,
+    _elem_ty: PhantomData<&'a mut T>,
+    _link_ty: PhantomData<L>,
+}
+
+/// An owning iterator over the items of a [`List`].
+///
+/// Created by [`List`]'s `IntoIterator` implementation.
+///
+/// [`List`]: ../struct.List.html
+pub struct IntoIter<T, N, R, L> {
+    list: List<T, N, R, L>,
+}
+
+// ===== impl List =====
+
+impl<T, N, R, L> List<T, N, R, L>
+where
+    N: AsRef<T>,
+{
+    /// Returns an iterator over references to this list's items, from
+    /// head to tail.
+    pub fn iter(&self) -> Iter<'_, T, N, L> {
+        Iter {
+            head: self.head(),
+            tail: self.tail(),
+            len: self.len,
+            _elem_ty: PhantomData,
+            _link_ty: PhantomData,
+        }
+    }
+}
+
+impl<T, N, R, L> List<T, N, R, L>
+where
+    N: AsMut<T>,
+{
+    /// Returns an iterator over mutable references to this list's items,
+    /// from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T, N, L> {
+        IterMut {
+            head: self.head,
+            tail: self.tail,
+            len: self.len,
+            _elem_ty: PhantomData,
+            _link_ty: PhantomData,
+        }
+    }
+}
+
+impl<T, N, R, L> IntoIterator for List<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N>,
+{
+    type Item = R;
+    type IntoIter = IntoIter<T, N, R, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+// ===== impl Iter =====
+
+impl<'a, T, N, L> Iterator for Iter<'a, T, N, L>
+where
+    N: AsRef<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.head?;
+        self.head = L::get_links(node).as_ref();
+        self.len -= 1;
+        Some(node.as_ref())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, N, L> DoubleEndedIterator for Iter<'a, T, N, L>
+where
+    N: AsRef<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.tail?;
+        self.tail = L::get_prev_links(node).as_ref();
+        self.len -= 1;
+        Some(node.as_ref())
+    }
+}
+
+impl<'a, T, N, L> ExactSizeIterator for Iter<'a, T, N, L>
+where
+    N: AsRef<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+}
+impl<'a, T, N, L> FusedIterator for Iter<'a, T, N, L>
+where
+    N: AsRef<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+}
+
+// ===== impl IterMut =====
+
+impl<'a, T, N, L> Iterator for IterMut<'a, T, N, L>
+where
+    N: AsMut<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.head.as_ptr()?;
+            self.head = *L::get_links(&*ptr);
+            self.len -= 1;
+            Some((&mut *ptr).as_mut())
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T, N, L> DoubleEndedIterator for IterMut<'a, T, N, L>
+where
+    N: AsMut<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        unsafe {
+            let ptr = self.tail.as_ptr()?;
+            self.tail = *L::get_prev_links(&*ptr);
+            self.len -= 1;
+            Some((&mut *ptr).as_mut())
+        }
+    }
+}
+
+impl<'a, T, N, L> ExactSizeIterator for IterMut<'a, T, N, L>
+where
+    N: AsMut<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+}
+impl<'a, T, N, L> FusedIterator for IterMut<'a, T, N, L>
+where
+    N: AsMut<T> + 'a,
+    L: GetLinks<Node = N>,
+{
+}
+
+// ===== impl IntoIter =====
+
+impl<T, N, R, L> Iterator for IntoIter<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N>,
+{
+    type Item = R;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front_node()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, N, R, L> DoubleEndedIterator for IntoIter<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back_node()
+    }
+}
+
+impl<T, N, R, L> ExactSizeIterator for IntoIter<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N>,
+{
+}
+
+impl<T, N, R, L> FusedIterator for IntoIter<T, N, R, L>
+where
+    L: GetLinks<Node = N>,
+    R: OwningRef<N>,
+{
+}
@@ -7,10 +7,28 @@
 //! use intrusive lists in code that runs without the kernel memory allocator,
 //! like the allocator implementation itself, since each list element manages
 //! its own memory.
+//!
+//! `List` is doubly-linked: it stores both a `head` and a `tail` [`Link`], so
+//! that elements can be pushed and popped from either end of the list in
+//! constant time, mirroring the head/tail design used by other intrusive
+//! lists such as `tokio`'s and `cordyceps`'s.
+//!
+//! `List` is also generic over how a node's links are found, via the
+//! [`GetLinks`] selector. This lets a single node type embed more than one
+//! set of links and be a member of more than one list at once; see
+//! [`GetLinks`] for details.
 use super::{Link, OwningRef};
 use core::marker::PhantomData;
 use core::mem;
 use core::ops::DerefMut;
+use core::pin::Pin;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+mod cursor;
+mod iter;
+pub use self::cursor::CursorMut;
+pub use self::iter::{IntoIter, Iter, IterMut};
+
 #[cfg(test)]
 mod tests;
 
@@ -18,7 +36,7 @@ mod tests;
 // Public API types
 //-----------------------------------------------------------------------------
 //  List
-/// An intrusive singly-linked list.
+/// An intrusive doubly-linked list.
 ///
 /// This type is a wrapper around a series of [`Node`]s. It stores [`Link`]s
 /// to the head and tail [`Node`]s and the length of the list.
@@ -27,15 +45,22 @@ mod tests;
 /// - `T`: the type of the items stored by each `N`
 /// - `N`: the type of nodes in the list
 /// - `R`: the type of [`OwningRef`] that owns each `N`.
+/// - `L`: the [`GetLinks`] selector used to find `N`'s links. Defaults to
+///   [`Identity`], which uses `N`'s own [`Linked`] implementation; only
+///   nodes that are members of more than one list at once need to name a
+///   different selector.
 ///
 /// [`Node`]: trait.Node.html
 /// [`Link`]: ../struct.Link.html
 /// [`OwningRef]: ../trait.OwningRef.html
 #[derive(Default)]
-pub struct List<T, N, R> {
+pub struct List<T, N, R, L = Identity<N>> {
     /// Link to the head node of the list.
     head: Link<N>,
 
+    /// Link to the tail node of the list.
+    tail: Link<N>,
+
     /// Length of the list.
     len: usize,
 
@@ -44,11 +69,28 @@ pub struct List<T, N, R> {
 
     /// Type marker for the `OwningRef` type.
     _ref_ty: PhantomData<R>,
+
+    /// Type marker for the `GetLinks` selector used by this list.
+    _link_ty: PhantomData<L>,
 }
 
 //  Linked
 /// Trait that must be implemented in order to be a member of an intrusive
 /// linked list.
+///
+/// This is the right trait to implement for a node that will only ever be
+/// a member of a single list. A node that needs to be a member of more
+/// than one list at once (by embedding more than one set of links) should
+/// implement [`GetLinks`] once per list instead.
+///
+/// A node linked into a list must never move in memory until it is
+/// unlinked. `List` itself has no way to enforce this — its `Link<N>`
+/// doesn't embed a `PhantomPinned` — so a type that will be pushed with
+/// `push_front_pinned`/`push_back_pinned` must embed a `PhantomPinned`
+/// field of its own, so that safe code can't obtain an unpinned `&mut N`
+/// to it while it's linked. Without that field, `push_front_pinned`/
+/// `push_back_pinned` are no safer than the plain, unpinned push
+/// methods.
 pub trait Linked: Sized // + Drop
 {
     /// Borrow this element's [`Link`].
@@ -61,11 +103,51 @@ pub trait Linked: Sized // + Drop
     /// [`Links`]: struct.Links.html
     fn links_mut(&mut self) -> &mut Link<Self>;
 
+    /// Borrow this element's `prev` [`Link`].
+    ///
+    /// [`Link`]: ../struct.Link.html
+    fn prev_links(&self) -> &Link<Self>;
+
+    /// Mutably borrow this element's `prev` [`Link`].
+    ///
+    /// [`Link`]: ../struct.Link.html
+    fn prev_links_mut(&mut self) -> &mut Link<Self>;
+
+    /// Borrow this node's "inserted" flag.
+    ///
+    /// Implementors store this flag (typically an `AtomicBool` field, or a
+    /// `Cell<bool>` on targets without atomics) alongside their `Link`s. It
+    /// guards against linking a node into two lists, or twice into the same
+    /// list, at once — either of which would otherwise silently corrupt
+    /// both lists.
+    fn is_inserted(&self) -> &AtomicBool;
+
+    /// Atomically claims this node for insertion, returning `false` if it
+    /// was already linked into a list.
+    #[inline]
+    fn mark_inserted(&self) -> bool {
+        self.is_inserted()
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+    }
+
+    /// Clears this node's "inserted" flag. Called whenever the node is
+    /// unlinked from a list.
+    #[inline]
+    fn mark_removed(&self) {
+        self.is_inserted().store(false, Ordering::Release);
+    }
+
     /// De-link this node, returning its' Links.
     fn take_links(&mut self) -> Link<Self> {
         mem::replace(self.links_mut(), Link::none())
     }
 
+    /// De-link this node's `prev` pointer, returning it.
+    fn take_prev(&mut self) -> Link<Self> {
+        mem::replace(self.prev_links_mut(), Link::none())
+    }
+
     /// Borrow the `next` element in the list, or `None` if this is the
     /// last.
     #[inline]
@@ -80,6 +162,50 @@ pub trait Linked: Sized // + Drop
         self.links_mut().as_mut()
     }
 
+    /// Borrow the `prev` element in the list, or `None` if this is the
+    /// first.
+    #[inline]
+    fn prev(&self) -> Option<&Self> {
+        self.prev_links().as_ref()
+    }
+
+    /// Mutably borrow the `prev` element in the list, or `None` if this is
+    /// the first.
+    #[inline]
+    fn prev_mut(&mut self) -> Option<&mut Self> {
+        self.prev_links_mut().as_mut()
+    }
+
+    /// Detach this node from its neighbors, returning the former
+    /// `(prev, next)` links.
+    ///
+    /// This splices the node out of whatever list it is linked into by
+    /// pointing its neighbors at each other, but it cannot fix up the
+    /// owning [`List`]'s `head`/`tail` pointers: the caller is responsible
+    /// for doing so when either returned link is `None`, meaning this node
+    /// was an endpoint.
+    ///
+    /// [`List`]: struct.List.html
+    ///
+    /// # Safety
+    /// The caller must ensure that `self` is currently linked into a list,
+    /// and that any `head`/`tail` links pointing at `self` are fixed up
+    /// afterwards.
+    unsafe fn unlink(&mut self) -> (Link<Self>, Link<Self>) {
+        let prev = self.take_prev();
+        let next = self.take_links();
+
+        if let Some(prev) = prev.as_ptr() {
+            *(*prev).links_mut() = next;
+        }
+        if let Some(next) = next.as_ptr() {
+            *(*next).prev_links_mut() = prev;
+        }
+
+        self.mark_removed();
+        (prev, next)
+    }
+
     /// Borrow the `next` linked element, or `None` if this is the last.
     #[inline]
     fn peek_next<T>(&self) -> Option<&T>
@@ -98,6 +224,138 @@ pub trait Linked: Sized // + Drop
     {
         self.next_mut().map(Self::as_mut)
     }
+
+    /// Borrow the `prev` linked element, or `None` if this is the first.
+    #[inline]
+    fn peek_prev<T>(&self) -> Option<&T>
+    where
+        Self: AsRef<T>,
+    {
+        self.prev().map(Self::as_ref)
+    }
+
+    /// Mutably borrow the `prev` linked element, or `None` if this is the
+    /// first.
+    #[inline]
+    fn peek_prev_mut<T>(&mut self) -> Option<&mut T>
+    where
+        Self: AsMut<T>,
+    {
+        self.prev_mut().map(Self::as_mut)
+    }
+}
+
+//  GetLinks
+/// Selects one of a node's (possibly several) sets of list links.
+///
+/// [`Linked`] lets a node be a member of a single list: it hard-codes one
+/// pair of `next`/`prev` [`Link`]s (and one "inserted" flag) per node.
+/// `GetLinks` lifts that restriction by moving link selection out of the
+/// node type and into a separate, typically zero-sized, marker type that
+/// [`List`] is parameterized over. A node that embeds two distinct sets of
+/// links (say, one for a size-bucketed free list and one for a global
+/// all-blocks list) can implement `GetLinks` once per marker, selecting a
+/// different field each time, and be a member of both lists
+/// simultaneously — something that's impossible when `Linked` hard-codes a
+/// single link per type.
+///
+/// [`List`] defaults its selector parameter to [`Identity`], which simply
+/// forwards to a node's own [`Linked`] implementation, so ordinary
+/// single-list usage is unaffected.
+///
+/// [`List`]: struct.List.html
+pub trait GetLinks {
+    /// The node type this selector operates over.
+    type Node;
+
+    /// Borrow the selected `next` [`Link`].
+    fn get_links(node: &Self::Node) -> &Link<Self::Node>;
+
+    /// Mutably borrow the selected `next` [`Link`].
+    fn get_links_mut(node: &mut Self::Node) -> &mut Link<Self::Node>;
+
+    /// Borrow the selected `prev` [`Link`].
+    fn get_prev_links(node: &Self::Node) -> &Link<Self::Node>;
+
+    /// Mutably borrow the selected `prev` [`Link`].
+    fn get_prev_links_mut(node: &mut Self::Node) -> &mut Link<Self::Node>;
+
+    /// Borrow the selected "inserted" flag.
+    fn get_inserted(node: &Self::Node) -> &AtomicBool;
+}
+
+/// The default [`GetLinks`] selector, for nodes that implement [`Linked`]
+/// directly and are only ever a member of a single list.
+///
+/// [`List`]'s selector parameter defaults to `Identity<N>`, so this type is
+/// rarely named explicitly.
+///
+/// [`List`]: struct.List.html
+pub struct Identity<N>(PhantomData<fn(&N)>);
+
+impl<N: Linked> GetLinks for Identity<N> {
+    type Node = N;
+
+    #[inline]
+    fn get_links(node: &N) -> &Link<N> {
+        node.links()
+    }
+
+    #[inline]
+    fn get_links_mut(node: &mut N) -> &mut Link<N> {
+        node.links_mut()
+    }
+
+    #[inline]
+    fn get_prev_links(node: &N) -> &Link<N> {
+        node.prev_links()
+    }
+
+    #[inline]
+    fn get_prev_links_mut(node: &mut N) -> &mut Link<N> {
+        node.prev_links_mut()
+    }
+
+    #[inline]
+    fn get_inserted(node: &N) -> &AtomicBool {
+        node.is_inserted()
+    }
+}
+
+#[inline]
+fn mark_inserted<L: GetLinks>(node: &L::Node) -> bool {
+    L::get_inserted(node)
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_ok()
+}
+
+#[inline]
+fn mark_removed<L: GetLinks>(node: &L::Node) {
+    L::get_inserted(node).store(false, Ordering::Release);
+}
+
+/// Detaches `node` from its neighbors, as selected by `L`, returning the
+/// former `(prev, next)` links so the caller can fix up a [`List`]'s
+/// `head`/`tail` when `node` was an endpoint.
+///
+/// [`List`]: struct.List.html
+///
+/// # Safety
+/// The caller must ensure `node` is linked into a list via the `L`
+/// selector, and must fix up that list's `head`/`tail` links afterwards.
+unsafe fn unlink_via<L: GetLinks>(node: &mut L::Node) -> (Link<L::Node>, Link<L::Node>) {
+    let prev = mem::replace(L::get_prev_links_mut(node), Link::none());
+    let next = mem::replace(L::get_links_mut(node), Link::none());
+
+    if let Some(prev) = prev.as_ptr() {
+        *L::get_links_mut(&mut *prev) = next;
+    }
+    if let Some(next) = next.as_ptr() {
+        *L::get_prev_links_mut(&mut *next) = prev;
+    }
+
+    mark_removed::<L>(node);
+    (prev, next)
 }
 
 //-----------------------------------------------------------------------------
@@ -106,14 +364,16 @@ pub trait Linked: Sized // + Drop
 
 // ===== impl List =====
 
-impl<T, Node, R> List<T, Node, R> {
+impl<T, Node, R, L> List<T, Node, R, L> {
     /// Create a new `List` with 0 elements.
     pub const fn new() -> Self {
         List {
             head: Link::none(),
+            tail: Link::none(),
             len: 0,
             _elem_ty: PhantomData,
             _ref_ty: PhantomData,
+            _link_ty: PhantomData,
         }
     }
 
@@ -151,62 +411,228 @@ impl<T, Node, R> List<T, Node, R> {
     pub fn head_mut(&mut self) -> Option<&mut Node> {
         self.head.as_mut()
     }
+
+    /// Borrows the last node of the list as an `Option`.
+    ///
+    /// Note that this is distinct from `back`: this method
+    /// borrows the tail _node_, not the tail _element_.
+    ///
+    /// # Returns
+    ///   - `Some(&N)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn tail(&self) -> Option<&Node> {
+        self.tail.as_ref()
+    }
+
+    /// Mutably borrows the last node of the list as an `Option`
+    ///
+    /// # Returns
+    ///   - `Some(&mut Node)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn tail_mut(&mut self) -> Option<&mut Node> {
+        self.tail.as_mut()
+    }
 }
 
-impl<T, Node, Ref> List<T, Node, Ref>
+impl<T, Node, Ref, L> List<T, Node, Ref, L>
 where
-    Node: Linked,
+    L: GetLinks<Node = Node>,
     Ref: OwningRef<Node>,
     Ref: DerefMut,
 {
     /// Push a node to the head of the list.
-    pub fn push_front_node(&mut self, mut node: Ref) -> &mut Self {
+    ///
+    /// # Errors
+    /// If `node` is already linked into a list (including this one),
+    /// linking it in again would corrupt both lists, so the node is
+    /// rejected and handed back as `Err(node)`.
+    pub fn push_front_node(&mut self, mut node: Ref) -> Result<&mut Self, Ref> {
+        if !mark_inserted::<L>(&*node) {
+            return Err(node);
+        }
         unsafe {
-            /*
-                Link is also a struct, with Optional interface
-                What is this `node`?
-            */
-            *node.links_mut() = self.head;
+            *L::get_links_mut(&mut *node) = self.head;
 
             let node = Link::from_owning_ref(node);
 
-            //TODO: What about this ()?
             match self.head.0 {
-                None => (),  //FIXME
-                Some(mut head) => ()  //FIXME
+                // The list was empty: the new node is also the tail.
+                None => {
+                    self.tail = node;
+                }
+                // The list already had a head: link it back to the new node.
+                Some(head) => {
+                    *L::get_prev_links_mut(&mut *head.as_ptr()) = node;
+                }
             }
 
             self.head = node;
             self.len += 1;
         };
-        self
+        Ok(self)
+    }
+
+    /// Push a node to the tail of the list.
+    ///
+    /// # Errors
+    /// If `node` is already linked into a list (including this one),
+    /// linking it in again would corrupt both lists, so the node is
+    /// rejected and handed back as `Err(node)`.
+    pub fn push_back_node(&mut self, mut node: Ref) -> Result<&mut Self, Ref> {
+        if !mark_inserted::<L>(&*node) {
+            return Err(node);
+        }
+        unsafe {
+            *L::get_prev_links_mut(&mut *node) = self.tail;
+
+            let node = Link::from_owning_ref(node);
+
+            match self.tail.0 {
+                // The list was empty: the new node is also the head.
+                None => {
+                    self.head = node;
+                }
+                // The list already had a tail: link it forward to the new node.
+                Some(tail) => {
+                    *L::get_links_mut(&mut *tail.as_ptr()) = node;
+                }
+            }
+
+            self.tail = node;
+            self.len += 1;
+        };
+        Ok(self)
+    }
+
+    /// Push a pinned node to the head of the list.
+    ///
+    /// Intrusive lists require that a linked node never move in memory:
+    /// `push_front_node` already stashes a raw pointer to `node`, but
+    /// nothing stops a caller from later moving it out of its `Ref`. This
+    /// variant accepts a `Pin<Ref>` instead, which closes that hole *if*
+    /// `N` is `!Unpin` — if `N` embeds a `PhantomPinned` field, as
+    /// [`Linked`] documents. If `N` is `Unpin`, `Pin<Ref>` grants no
+    /// additional guarantee: safe code can still move it back out via
+    /// `Pin::into_inner`, so this variant is only as sound as the node
+    /// type makes it.
+    ///
+    /// [`Linked`]: trait.Linked.html
+    ///
+    /// # Safety
+    /// The caller must not move `node`'s pointee for as long as it stays
+    /// linked into this (or any) list.
+    pub unsafe fn push_front_pinned(&mut self, node: Pin<Ref>) -> Result<&mut Self, Pin<Ref>> {
+        let node = Pin::into_inner_unchecked(node);
+        match self.push_front_node(node) {
+            Ok(_) => Ok(self),
+            Err(node) => Err(Pin::new_unchecked(node)),
+        }
+    }
+
+    /// Push a pinned node to the tail of the list.
+    ///
+    /// See [`push_front_pinned`] for the invariant this upholds, and the
+    /// `N: !Unpin` caveat.
+    ///
+    /// [`push_front_pinned`]: #method.push_front_pinned
+    ///
+    /// # Safety
+    /// The caller must not move `node`'s pointee for as long as it stays
+    /// linked into this (or any) list.
+    pub unsafe fn push_back_pinned(&mut self, node: Pin<Ref>) -> Result<&mut Self, Pin<Ref>> {
+        let node = Pin::into_inner_unchecked(node);
+        match self.push_back_node(node) {
+            Ok(_) => Ok(self),
+            Err(node) => Err(Pin::new_unchecked(node)),
+        }
     }
 }
 
-impl<T, Node, Ref> List<T, Node, Ref>
+impl<T, Node, Ref, L> List<T, Node, Ref, L>
 where
-    Node: Linked,
+    L: GetLinks<Node = Node>,
     Ref: OwningRef<Node>,
 {
     /// Pop a node from the front of the list.
     pub fn pop_front_node(&mut self) -> Option<Ref> {
         unsafe {
             self.head.as_ptr().map(|node| {
-                self.head = (*node).take_links();
+                self.head = mem::replace(L::get_links_mut(&mut *node), Link::none());
 
                 match self.head.as_mut() {
-                    None => (),
-                    Some(head) => ()
+                    // The list is now empty: clear the tail too.
+                    None => {
+                        self.tail = Link::none();
+                    }
+                    // The new head has no `prev` node.
+                    Some(head) => {
+                        *L::get_prev_links_mut(head) = Link::none();
+                    }
+                }
+
+                mark_removed::<L>(&*node);
+                self.len -= 1;
+                Ref::from_ptr(node as *const Node)
+            })
+        }
+    }
+
+    /// Pop a node from the back of the list.
+    pub fn pop_back_node(&mut self) -> Option<Ref> {
+        unsafe {
+            self.tail.as_ptr().map(|node| {
+                self.tail = mem::replace(L::get_prev_links_mut(&mut *node), Link::none());
+
+                match self.tail.as_mut() {
+                    // The list is now empty: clear the head too.
+                    None => {
+                        self.head = Link::none();
+                    }
+                    // The new tail has no `next` node.
+                    Some(tail) => {
+                        *L::get_links_mut(tail) = Link::none();
+                    }
                 }
 
+                mark_removed::<L>(&*node);
                 self.len -= 1;
                 Ref::from_ptr(node as *const Node)
             })
         }
     }
+
+    /// Removes `node` from this list in O(1), returning the owning [`Ref`]
+    /// that had been handed to the list when `node` was inserted.
+    ///
+    /// Unlike `pop_front_node`/`pop_back_node`, this does not require
+    /// walking the list from either end: the node's own `prev`/`next`
+    /// links are enough to splice it out and fix up `head`/`tail` if it
+    /// was an endpoint.
+    ///
+    /// [`Ref`]: ../trait.OwningRef.html
+    ///
+    /// # Safety
+    /// The caller must guarantee that `node` is currently a member of
+    /// *this* list. Passing a node that is unlinked, or linked into some
+    /// other list, is undefined behaviour.
+    pub unsafe fn remove_node(&mut self, node: &mut Node) -> Option<Ref> {
+        let (prev, next) = unlink_via::<L>(node);
+
+        if prev.as_ptr().is_none() {
+            self.head = next;
+        }
+        if next.as_ptr().is_none() {
+            self.tail = prev;
+        }
+
+        self.len -= 1;
+        Some(Ref::from_ptr(node as *const Node))
+    }
 }
 
-impl<T, Node, R> List<T, Node, R>
+impl<T, Node, R, L> List<T, Node, R, L>
 where
     Node: AsRef<T>,
 {
@@ -219,9 +645,19 @@ where
     pub fn front(&self) -> Option<&T> {
         self.head().map(Node::as_ref)
     }
+
+    /// Borrows the last item of the list as an `Option`
+    ///
+    /// # Returns
+    ///   - `Some(&T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn back(&self) -> Option<&T> {
+        self.tail().map(Node::as_ref)
+    }
 }
 
-impl<T, Node, R> List<T, Node, R>
+impl<T, Node, R, L> List<T, Node, R, L>
 where
     Node: AsMut<T>,
 {
@@ -234,6 +670,16 @@ where
     pub fn front_mut(&mut self) -> Option<&mut T> {
         self.head_mut().map(Node::as_mut)
     }
+
+    /// Mutably borrows the last element of the list as an `Option`
+    ///
+    /// # Returns
+    ///   - `Some(&mut T)` if the list has elements
+    ///   - `None` if the list is empty.
+    #[inline]
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.tail_mut().map(Node::as_mut)
+    }
 }
 
 #[cfg(all(
@@ -255,6 +701,16 @@ where
     #[inline]
     pub fn push_front(&mut self, item: T) -> &mut Self {
         self.push_front_node(Box::new(Node::from(item)))
+            .ok()
+            .expect("a freshly allocated node cannot already be linked")
+    }
+
+    /// Push an item to the back of the list.
+    #[inline]
+    pub fn push_back(&mut self, item: T) -> &mut Self {
+        self.push_back_node(Box::new(Node::from(item)))
+            .ok()
+            .expect("a freshly allocated node cannot already be linked")
     }
 }
 
@@ -269,4 +725,10 @@ where
     pub fn pop_front(&mut self) -> Option<T> {
         self.pop_front_node().map(|b| (*b).into())
     }
-}
\ No newline at end of file
+
+    /// Pop an item from the back of the list.
+    #[inline]
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.pop_back_node().map(|b| (*b).into())
+    }
+}
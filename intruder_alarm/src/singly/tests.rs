@@ -0,0 +1,420 @@
+use super::*;
+use core::sync::atomic::AtomicBool;
+use std::boxed::Box;
+
+#[derive(Default, Debug)]
+pub struct NumberedNode {
+    pub number: usize,
+    next: Link<NumberedNode>,
+    prev: Link<NumberedNode>,
+    inserted: AtomicBool,
+}
+
+impl NumberedNode {
+    pub fn new(number: usize) -> Self {
+        NumberedNode {
+            number,
+            ..Default::default()
+        }
+    }
+}
+
+impl Linked for NumberedNode {
+    #[inline]
+    fn links(&self) -> &Link<Self> {
+        &self.next
+    }
+
+    #[inline]
+    fn links_mut(&mut self) -> &mut Link<Self> {
+        &mut self.next
+    }
+
+    #[inline]
+    fn prev_links(&self) -> &Link<Self> {
+        &self.prev
+    }
+
+    #[inline]
+    fn prev_links_mut(&mut self) -> &mut Link<Self> {
+        &mut self.prev
+    }
+
+    #[inline]
+    fn is_inserted(&self) -> &AtomicBool {
+        &self.inserted
+    }
+}
+
+impl AsRef<usize> for NumberedNode {
+    fn as_ref(&self) -> &usize {
+        &self.number
+    }
+}
+
+impl AsMut<usize> for NumberedNode {
+    fn as_mut(&mut self) -> &mut usize {
+        &mut self.number
+    }
+}
+
+impl PartialEq for NumberedNode {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.number == rhs.number
+    }
+}
+
+impl From<usize> for NumberedNode {
+    fn from(u: usize) -> NumberedNode {
+        NumberedNode::new(u)
+    }
+}
+
+impl Into<usize> for NumberedNode {
+    fn into(self) -> usize {
+        self.number
+    }
+}
+
+type NumberedList = List<usize, NumberedNode, Box<NumberedNode>>;
+
+fn list_from(items: &[usize]) -> NumberedList {
+    let mut list = NumberedList::new();
+    for &i in items {
+        list.push_back(i);
+    }
+    list
+}
+
+mod push_pop {
+    use super::*;
+
+    #[test]
+    fn not_empty_after_first_push() {
+        let mut list = NumberedList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.len(), 0);
+
+        list.push_front(1);
+
+        assert!(!list.is_empty());
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&1));
+    }
+
+    #[test]
+    fn push_front_and_back_order() {
+        let mut list = NumberedList::new();
+        list.push_back(1);
+        list.push_back(2);
+        list.push_front(0);
+
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&2));
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn pop_front_and_back() {
+        let mut list = list_from(&[0, 1, 2]);
+
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn remove_node_unlinks_an_arbitrary_node() {
+        let mut list = list_from(&[0, 1, 2]);
+        let middle: *mut NumberedNode = list.head_mut().unwrap().next_mut().unwrap();
+
+        let removed = unsafe { list.remove_node(&mut *middle) }.unwrap();
+
+        assert_eq!(removed.number, 1);
+        assert_eq!(list.len(), 2);
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 2]);
+    }
+}
+
+mod iter {
+    use super::*;
+
+    #[test]
+    fn iter_visits_items_front_to_back() {
+        let list = list_from(&[0, 1, 2]);
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn iter_mut_allows_updating_items_in_place() {
+        let mut list = list_from(&[0, 1, 2]);
+        for item in list.iter_mut() {
+            *item += 10;
+        }
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn into_iter_drains_the_list() {
+        let list = list_from(&[0, 1, 2]);
+        let items: Vec<usize> = list.into_iter().map(|node| node.number).collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+}
+
+mod cursor {
+    use super::*;
+
+    #[test]
+    fn move_next_walks_to_the_end() {
+        let mut list = list_from(&[0, 1, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        assert_eq!(cursor.current(), Some(&mut 0));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+        cursor.move_next();
+        assert_eq!(cursor.current(), None, "cursor should be past the end");
+    }
+
+    #[test]
+    fn remove_current_advances_to_the_next_node() {
+        let mut list = list_from(&[0, 1, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        let removed = cursor.remove_current().unwrap();
+        assert_eq!(removed.number, 1);
+        assert_eq!(cursor.current(), Some(&mut 2));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn insert_after_links_the_node_in_after_current() {
+        let mut list = list_from(&[0, 2]);
+        let mut cursor = list.cursor_front_mut();
+
+        cursor.insert_after(Box::new(NumberedNode::new(1))).unwrap();
+
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn insert_before_links_the_node_in_before_current() {
+        let mut list = list_from(&[0, 2]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+
+        cursor.insert_before(Box::new(NumberedNode::new(1))).unwrap();
+
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn insert_after_past_the_end_pushes_to_the_front() {
+        let mut list = list_from(&[0]);
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+
+        cursor.insert_after(Box::new(NumberedNode::new(1))).unwrap();
+
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.len(), 2);
+    }
+}
+
+/// Exercises the `inserted` flag that [`push_front_node`]/[`push_back_node`]
+/// check before linking a node in, using an [`UnsafeRef`]-backed list so
+/// the same node can be handed to a second list while still linked into
+/// the first — the same sharing [`lru::LruCache`] relies on, just without
+/// the removal step.
+///
+/// [`push_front_node`]: ../struct.List.html#method.push_front_node
+/// [`push_back_node`]: ../struct.List.html#method.push_back_node
+/// [`UnsafeRef`]: ../struct.UnsafeRef.html
+/// [`lru::LruCache`]: ../lru/struct.LruCache.html
+mod already_inserted {
+    use super::*;
+    use UnsafeRef;
+
+    type UnsafeNumberedList = List<usize, NumberedNode, UnsafeRef<NumberedNode>>;
+
+    #[test]
+    fn pushing_an_already_linked_node_is_rejected() {
+        let mut list_a = UnsafeNumberedList::new();
+        let node = UnsafeRef::boxed(NumberedNode::new(0));
+
+        list_a.push_front_node(node).unwrap();
+        assert_eq!(list_a.len(), 1);
+
+        let mut list_b = UnsafeNumberedList::new();
+        let err = list_b.push_front_node(node).err().unwrap();
+
+        assert_eq!(err.number, 0, "the rejected node should be handed back unchanged");
+        assert!(
+            list_b.is_empty(),
+            "the second list must not have linked the already-inserted node in"
+        );
+        assert_eq!(
+            list_a.len(),
+            1,
+            "the first list must be untouched by the rejected push"
+        );
+    }
+}
+
+/// Exercises [`push_front_pinned`]/[`push_back_pinned`], using an
+/// [`UnsafeRef`]-backed list since `Pin<UnsafeRef<N>>` is `Copy`, so the
+/// same node can be handed to a second list to exercise the rejection
+/// path the same way [`already_inserted`] does for the unpinned push.
+///
+/// [`push_front_pinned`]: ../struct.List.html#method.push_front_pinned
+/// [`push_back_pinned`]: ../struct.List.html#method.push_back_pinned
+/// [`UnsafeRef`]: ../struct.UnsafeRef.html
+mod pinned {
+    use super::*;
+    use UnsafeRef;
+
+    type UnsafeNumberedList = List<usize, NumberedNode, UnsafeRef<NumberedNode>>;
+
+    #[test]
+    fn push_pinned_links_the_node_and_pop_unlinks_it() {
+        let mut list = UnsafeNumberedList::new();
+        let node = Pin::new(UnsafeRef::boxed(NumberedNode::new(0)));
+
+        unsafe { list.push_front_pinned(node) }.unwrap();
+
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.len(), 1);
+
+        let popped = list.pop_front_node().unwrap();
+        assert_eq!(popped.number, 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn push_pinned_rejects_an_already_linked_node() {
+        let mut list_a = UnsafeNumberedList::new();
+        let node = UnsafeRef::boxed(NumberedNode::new(0));
+
+        unsafe { list_a.push_front_pinned(Pin::new(node)) }.unwrap();
+
+        let mut list_b = UnsafeNumberedList::new();
+        let err = unsafe { list_b.push_back_pinned(Pin::new(node)) }.err().unwrap();
+
+        assert_eq!(err.number, 0, "the rejected node should be handed back unchanged");
+        assert!(
+            list_b.is_empty(),
+            "the second list must not have linked the already-inserted node in"
+        );
+        assert_eq!(
+            list_a.len(),
+            1,
+            "the first list must be untouched by the rejected push"
+        );
+    }
+}
+
+/// Exercises a node with a second [`GetLinks`] selector, confirming that
+/// `CursorMut` and the iterators work for lists keyed by something other
+/// than the default [`Identity`] selector.
+mod get_links {
+    use super::*;
+
+    #[derive(Default, Debug)]
+    struct SecondaryNode {
+        number: usize,
+        next: Link<SecondaryNode>,
+        prev: Link<SecondaryNode>,
+        inserted: AtomicBool,
+    }
+
+    impl AsRef<usize> for SecondaryNode {
+        fn as_ref(&self) -> &usize {
+            &self.number
+        }
+    }
+
+    /// An alternate [`GetLinks`] selector over [`SecondaryNode`]'s own
+    /// link fields, standing in for a second set of links a node could
+    /// embed to be a member of more than one list at once.
+    struct BySecondary;
+
+    impl GetLinks for BySecondary {
+        type Node = SecondaryNode;
+
+        fn get_links(node: &SecondaryNode) -> &Link<SecondaryNode> {
+            &node.next
+        }
+
+        fn get_links_mut(node: &mut SecondaryNode) -> &mut Link<SecondaryNode> {
+            &mut node.next
+        }
+
+        fn get_prev_links(node: &SecondaryNode) -> &Link<SecondaryNode> {
+            &node.prev
+        }
+
+        fn get_prev_links_mut(node: &mut SecondaryNode) -> &mut Link<SecondaryNode> {
+            &mut node.prev
+        }
+
+        fn get_inserted(node: &SecondaryNode) -> &AtomicBool {
+            &node.inserted
+        }
+    }
+
+    type SecondaryList = List<usize, SecondaryNode, Box<SecondaryNode>, BySecondary>;
+
+    #[test]
+    fn push_pop_work_with_a_non_default_selector() {
+        let mut list = SecondaryList::new();
+        list.push_back_node(Box::new(SecondaryNode {
+            number: 0,
+            ..Default::default()
+        }))
+        .unwrap();
+        list.push_back_node(Box::new(SecondaryNode {
+            number: 1,
+            ..Default::default()
+        }))
+        .unwrap();
+
+        assert_eq!(list.front(), Some(&0));
+        assert_eq!(list.back(), Some(&1));
+        assert_eq!(list.pop_front_node().unwrap().number, 0);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn cursor_and_iter_walk_a_non_default_selector() {
+        let mut list = SecondaryList::new();
+        for i in 0..3 {
+            list.push_back_node(Box::new(SecondaryNode {
+                number: i,
+                ..Default::default()
+            }))
+            .unwrap();
+        }
+
+        let items: Vec<usize> = list.iter().cloned().collect();
+        assert_eq!(items, vec![0, 1, 2]);
+
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        let removed = cursor.remove_current().unwrap();
+        assert_eq!(removed.number, 1);
+        assert_eq!(list.len(), 2);
+    }
+}
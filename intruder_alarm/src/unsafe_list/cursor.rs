@@ -0,0 +1,301 @@
+//! A cursor over an [`UnsafeList`], allowing in-place traversal, insertion,
+//! and removal.
+//!
+//! [`UnsafeList`]: ../struct.UnsafeList.html
+use super::{Link, Links, OwningRef, UnsafeList, UnsafeListLinked, UnsafeRef};
+
+/// A read-only cursor over an [`UnsafeList`].
+///
+/// A cursor holds a position at a node, or at the "ghost" position past
+/// either end of the list; advancing past the last node (or retreating
+/// past the first) moves the cursor to the ghost position, and advancing
+/// again wraps around to the other end.
+///
+/// Created by [`UnsafeList::cursor_front`] or [`UnsafeList::cursor_back`].
+///
+/// [`UnsafeList`]: ../struct.UnsafeList.html
+/// [`UnsafeList::cursor_front`]: ../struct.UnsafeList.html#method.cursor_front
+/// [`UnsafeList::cursor_back`]: ../struct.UnsafeList.html#method.cursor_back
+pub struct Cursor<'a, T: 'a> {
+    list: &'a UnsafeList<T>,
+    current: Link<T>,
+    index: usize,
+}
+
+/// A cursor over an [`UnsafeList`] that allows inspecting, inserting, and
+/// removing elements at an arbitrary position without walking the list
+/// again from the head.
+///
+/// Created by [`UnsafeList::cursor_front_mut`] or
+/// [`UnsafeList::cursor_back_mut`].
+///
+/// [`UnsafeList`]: ../struct.UnsafeList.html
+/// [`UnsafeList::cursor_front_mut`]: ../struct.UnsafeList.html#method.cursor_front_mut
+/// [`UnsafeList::cursor_back_mut`]: ../struct.UnsafeList.html#method.cursor_back_mut
+pub struct CursorMut<'a, T: 'a> {
+    list: &'a mut UnsafeList<T>,
+    current: Link<T>,
+    index: usize,
+}
+
+// ===== impl UnsafeList =====
+
+impl<T> UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    /// Returns a read-only cursor positioned at the front of the list.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        let current = if self.is_empty() {
+            Link::none()
+        } else {
+            unsafe { (*self.links_of(self.sentinel_ptr())).next }
+        };
+        Cursor {
+            list: self,
+            current,
+            index: 0,
+        }
+    }
+
+    /// Returns a read-only cursor positioned at the back of the list.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        let current = if self.is_empty() {
+            Link::none()
+        } else {
+            unsafe { (*self.links_of(self.sentinel_ptr())).prev }
+        };
+        Cursor {
+            list: self,
+            current,
+            index: self.len().wrapping_sub(1),
+        }
+    }
+
+    /// Returns a cursor positioned at the front of the list.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let sentinel = self.init();
+        let current = if self.is_empty() {
+            Link::none()
+        } else {
+            unsafe { (*self.links_of(sentinel)).next }
+        };
+        CursorMut {
+            list: self,
+            current,
+            index: 0,
+        }
+    }
+
+    /// Returns a cursor positioned at the back of the list.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let sentinel = self.init();
+        let current = if self.is_empty() {
+            Link::none()
+        } else {
+            unsafe { (*self.links_of(sentinel)).prev }
+        };
+        let index = self.len().wrapping_sub(1);
+        CursorMut {
+            list: self,
+            current,
+            index,
+        }
+    }
+}
+
+// ===== impl Cursor =====
+
+impl<'a, T> Cursor<'a, T>
+where
+    T: UnsafeListLinked,
+{
+    /// Advances the cursor to the next node in the list.
+    ///
+    /// If the cursor was at the ghost position, it moves to the front of
+    /// the list. If the cursor was at the last node, it moves to the
+    /// ghost position.
+    pub fn move_next(&mut self) {
+        let was_ghost = self.current.0.is_none();
+        self.current = match self.current.as_ptr() {
+            Some(ptr) => unsafe { self.list.next_of(ptr) },
+            None if self.list.is_empty() => Link::none(),
+            None => unsafe { (*self.list.links_of(self.list.sentinel_ptr())).next },
+        };
+        self.index = if self.current.0.is_none() {
+            self.list.len()
+        } else if was_ghost {
+            0
+        } else {
+            self.index.wrapping_add(1)
+        };
+    }
+
+    /// Moves the cursor to the previous node in the list.
+    ///
+    /// If the cursor was at the ghost position, it moves to the back of
+    /// the list. If the cursor was at the first node, it moves to the
+    /// ghost position.
+    pub fn move_prev(&mut self) {
+        self.current = match self.current.as_ptr() {
+            Some(ptr) => unsafe { self.list.prev_of(ptr) },
+            None if self.list.is_empty() => Link::none(),
+            None => unsafe { (*self.list.links_of(self.list.sentinel_ptr())).prev },
+        };
+        self.index = if self.current.0.is_some() {
+            self.index.wrapping_sub(1)
+        } else {
+            self.list.len()
+        };
+    }
+
+    /// Returns a reference to the element at the cursor's current
+    /// position, or `None` if the cursor is at the ghost position.
+    pub fn current(&self) -> Option<&T> {
+        self.current.as_ref()
+    }
+
+    /// Returns the index of the cursor's current position, or `None` if
+    /// the cursor is at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.0.is_some() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+}
+
+// ===== impl CursorMut =====
+
+impl<'a, T> CursorMut<'a, T>
+where
+    T: UnsafeListLinked,
+{
+    /// Advances the cursor to the next node in the list.
+    pub fn move_next(&mut self) {
+        let was_ghost = self.current.0.is_none();
+        self.current = match self.current.as_ptr() {
+            Some(ptr) => unsafe { self.list.next_of(ptr) },
+            None if self.list.is_empty() => Link::none(),
+            None => unsafe { (*self.list.links_of(self.list.sentinel_ptr())).next },
+        };
+        self.index = if self.current.0.is_none() {
+            self.list.len()
+        } else if was_ghost {
+            0
+        } else {
+            self.index.wrapping_add(1)
+        };
+    }
+
+    /// Moves the cursor to the previous node in the list.
+    pub fn move_prev(&mut self) {
+        let was_ghost = self.current.0.is_none();
+        self.current = match self.current.as_ptr() {
+            Some(ptr) => unsafe { self.list.prev_of(ptr) },
+            None if self.list.is_empty() => Link::none(),
+            None => unsafe { (*self.list.links_of(self.list.sentinel_ptr())).prev },
+        };
+        self.index = if self.current.0.is_none() {
+            self.list.len()
+        } else if was_ghost {
+            self.list.len() - 1
+        } else {
+            self.index.wrapping_sub(1)
+        };
+    }
+
+    /// Returns a mutable reference to the element at the cursor's current
+    /// position, or `None` if the cursor is at the ghost position.
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.as_mut()
+    }
+
+    /// Returns the index of the cursor's current position, or `None` if
+    /// the cursor is at the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        if self.current.0.is_some() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Removes the node at the cursor's current position and returns it,
+    /// advancing the cursor to the node that followed it (or to the
+    /// ghost position, if the removed node was the last one).
+    ///
+    /// Returns `None`, without moving the cursor, if it is at the ghost
+    /// position.
+    pub fn remove_current(&mut self) -> Option<UnsafeRef<T>> {
+        let ptr = self.current.as_ptr()?;
+        unsafe {
+            let next = self.list.next_of(ptr);
+            let removed = UnsafeRef::from_ptr(ptr as *const T);
+            self.list.remove(removed);
+            self.current = next;
+            self.index = if self.current.0.is_some() {
+                self.index
+            } else {
+                self.list.len()
+            };
+            Some(removed)
+        }
+    }
+
+    /// Inserts `node` immediately after the cursor's current position.
+    ///
+    /// If the cursor is at the ghost position, `node` is pushed onto the
+    /// front of the list instead, and the cursor is left at the ghost
+    /// position.
+    pub fn insert_after(&mut self, mut node: UnsafeRef<T>) {
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let next = (*self.list.links_of(ptr)).next;
+                    *node.links_mut() = Links {
+                        next,
+                        prev: self.current,
+                    };
+                    let node = Link::from_owning_ref(node);
+
+                    let next_ptr = next.as_ptr().expect("circular");
+                    (*self.list.links_of(next_ptr)).prev = node;
+                    (*self.list.links_of(ptr)).next = node;
+
+                    self.list.len += 1;
+                }
+                None => self.list.push_front_node(node),
+            }
+        }
+    }
+
+    /// Inserts `node` immediately before the cursor's current position.
+    ///
+    /// If the cursor is at the ghost position, `node` is pushed onto the
+    /// back of the list instead, and the cursor is left at the ghost
+    /// position.
+    pub fn insert_before(&mut self, mut node: UnsafeRef<T>) {
+        unsafe {
+            match self.current.as_ptr() {
+                Some(ptr) => {
+                    let prev = (*self.list.links_of(ptr)).prev;
+                    *node.links_mut() = Links {
+                        next: self.current,
+                        prev,
+                    };
+                    let node = Link::from_owning_ref(node);
+
+                    let prev_ptr = prev.as_ptr().expect("circular");
+                    (*self.list.links_of(prev_ptr)).next = node;
+                    (*self.list.links_of(ptr)).prev = node;
+
+                    self.list.len += 1;
+                    self.index = self.index.wrapping_add(1);
+                }
+                None => self.list.push_back_node(node),
+            }
+        }
+    }
+}
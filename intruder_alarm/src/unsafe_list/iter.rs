@@ -0,0 +1,243 @@
+//! Iterators over [`UnsafeList`]'s elements.
+//!
+//! [`UnsafeList`]: ../struct.UnsafeList.html
+use super::{Link, UnsafeList, UnsafeListLinked};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+
+/// An iterator over references to the items stored in an [`UnsafeList`].
+///
+/// Created by [`UnsafeList::iter`].
+///
+/// [`UnsafeList`]: ../struct.UnsafeList.html
+/// [`UnsafeList::iter`]: ../struct.UnsafeList.html#method.iter
+pub struct Iter<'a, T: 'a> {
+    sentinel: *mut T,
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+/// An iterator over mutable references to the items stored in an
+/// [`UnsafeList`].
+///
+/// Created by [`UnsafeList::iter_mut`].
+///
+/// [`UnsafeList`]: ../struct.UnsafeList.html
+/// [`UnsafeList::iter_mut`]: ../struct.UnsafeList.html#method.iter_mut
+pub struct IterMut<'a, T: 'a> {
+    sentinel: *mut T,
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+/// An owning iterator over the items of an [`UnsafeList`].
+///
+/// Created by [`UnsafeList`]'s `IntoIterator` implementation. Drains the
+/// list by repeatedly popping from the front.
+///
+/// [`UnsafeList`]: ../struct.UnsafeList.html
+pub struct IntoIter<T> {
+    list: UnsafeList<T>,
+}
+
+// ===== impl UnsafeList =====
+
+impl<T> UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    /// Returns an iterator over references to this list's items, from
+    /// head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        let (head, tail) = if self.is_empty() {
+            (Link::none(), Link::none())
+        } else {
+            (self.sentinel.next, self.sentinel.prev)
+        };
+        Iter {
+            sentinel: self.sentinel_ptr(),
+            head,
+            tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable references to this list's items,
+    /// from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (head, tail) = if self.is_empty() {
+            (Link::none(), Link::none())
+        } else {
+            (self.sentinel.next, self.sentinel.prev)
+        };
+        IterMut {
+            sentinel: self.sentinel_ptr(),
+            head,
+            tail,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> IntoIterator for UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    type Item = super::UnsafeRef<T>;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { list: self }
+    }
+}
+
+// ===== impl Iter =====
+
+impl<'a, T> Iterator for Iter<'a, T>
+where
+    T: UnsafeListLinked + 'a,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let ptr = self.head.as_ptr()?;
+        unsafe {
+            let next = (*ptr).links().next;
+            self.head = if next.as_ptr() == Some(self.sentinel) {
+                Link::none()
+            } else {
+                next
+            };
+            self.len -= 1;
+            Some(&*ptr)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T>
+where
+    T: UnsafeListLinked + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let ptr = self.tail.as_ptr()?;
+        unsafe {
+            let prev = (*ptr).links().prev;
+            self.tail = if prev.as_ptr() == Some(self.sentinel) {
+                Link::none()
+            } else {
+                prev
+            };
+            self.len -= 1;
+            Some(&*ptr)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> where T: UnsafeListLinked + 'a {}
+impl<'a, T> FusedIterator for Iter<'a, T> where T: UnsafeListLinked + 'a {}
+
+// ===== impl IterMut =====
+
+impl<'a, T> Iterator for IterMut<'a, T>
+where
+    T: UnsafeListLinked + 'a,
+{
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let ptr = self.head.as_ptr()?;
+        unsafe {
+            let next = (*ptr).links().next;
+            self.head = if next.as_ptr() == Some(self.sentinel) {
+                Link::none()
+            } else {
+                next
+            };
+            self.len -= 1;
+            Some(&mut *ptr)
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T>
+where
+    T: UnsafeListLinked + 'a,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let ptr = self.tail.as_ptr()?;
+        unsafe {
+            let prev = (*ptr).links().prev;
+            self.tail = if prev.as_ptr() == Some(self.sentinel) {
+                Link::none()
+            } else {
+                prev
+            };
+            self.len -= 1;
+            Some(&mut *ptr)
+        }
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> where T: UnsafeListLinked + 'a {}
+impl<'a, T> FusedIterator for IterMut<'a, T> where T: UnsafeListLinked + 'a {}
+
+// ===== impl IntoIter =====
+
+impl<T> Iterator for IntoIter<T>
+where
+    T: UnsafeListLinked,
+{
+    type Item = super::UnsafeRef<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.list.pop_front_node()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.list.len();
+        (len, Some(len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T>
+where
+    T: UnsafeListLinked,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.list.pop_back_node()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> where T: UnsafeListLinked {}
+impl<T> FusedIterator for IntoIter<T> where T: UnsafeListLinked {}
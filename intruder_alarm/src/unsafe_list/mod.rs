@@ -0,0 +1,393 @@
+//! An intrusive doubly-linked list backed entirely by [`UnsafeRef`]s.
+//!
+//! Unlike [`singly::List`] and [`doubly::List`], which are generic over the
+//! [`OwningRef`] type that backs each node, `UnsafeList` always stores its
+//! nodes behind an [`UnsafeRef`]. This is the shape needed by code like a
+//! scheduler run-queue or a wait-queue, where a node may need to be removed
+//! from the middle of the list by a thread other than the one that pushed
+//! it, which an owning `Box` cannot express safely.
+//!
+//! Internally, the list is circular and built around a sentinel: rather
+//! than `head`/`tail` being `Option<Link<T>>`-shaped and every operation
+//! branching on whether the list is empty, `UnsafeList` keeps a dummy
+//! [`Links`] pair whose `next` is the real head (or itself, if the list is
+//! empty) and whose `prev` is the real tail (or itself). Every splice then
+//! becomes an unconditional four-pointer rewrite against neighbors that
+//! always exist, matching the sentinel-node design used by circular
+//! intrusive lists such as SGX's `UnsafeList` and the Linux kernel's
+//! `list_head`.
+//!
+//! [`singly::List`]: ../singly/struct.List.html
+//! [`doubly::List`]: ../doubly/struct.List.html
+//! [`OwningRef`]: ../trait.OwningRef.html
+//! [`UnsafeRef`]: ../struct.UnsafeRef.html
+use super::{Link, OwningRef, UnsafeRef};
+use core::iter::FromIterator;
+use core::marker::PhantomData;
+use core::mem;
+use core::ptr::NonNull;
+
+mod cursor;
+pub use self::cursor::{Cursor, CursorMut};
+
+mod iter;
+pub use self::iter::{IntoIter, Iter, IterMut};
+
+#[cfg(test)]
+mod tests;
+
+//-----------------------------------------------------------------------------
+// Public API types
+//-----------------------------------------------------------------------------
+
+/// A node's `next` and `prev` [`Link`]s.
+///
+/// [`Link`]: ../struct.Link.html
+#[derive(Debug)]
+pub struct Links<T> {
+    next: Link<T>,
+    prev: Link<T>,
+}
+
+impl<T> Default for Links<T> {
+    fn default() -> Self {
+        Links {
+            next: Link::none(),
+            prev: Link::none(),
+        }
+    }
+}
+
+/// Trait that must be implemented in order to be a member of an
+/// [`UnsafeList`].
+///
+/// [`UnsafeList`]: struct.UnsafeList.html
+pub trait UnsafeListLinked: Sized {
+    /// Borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links(&self) -> &Links<Self>;
+
+    /// Mutably borrow this element's [`Links`].
+    ///
+    /// [`Links`]: struct.Links.html
+    fn links_mut(&mut self) -> &mut Links<Self>;
+}
+
+/// An intrusive doubly-linked list, always backed by [`UnsafeRef`]s.
+///
+/// `UnsafeList` is circular: the list owns a sentinel [`Links`] pair that
+/// is lazily linked to point at itself the first time the list is
+/// mutated. Once that has happened, `self` must not move for the rest of
+/// its lifetime, the same discipline `singly::List` documents for
+/// `Pin`-backed nodes, since the sentinel's `Links` record its own
+/// address.
+///
+/// [`UnsafeRef`]: ../struct.UnsafeRef.html
+pub struct UnsafeList<T> {
+    sentinel: Links<T>,
+    len: usize,
+    _elem_ty: PhantomData<T>,
+}
+
+//-----------------------------------------------------------------------------
+// Implementations
+//-----------------------------------------------------------------------------
+
+impl<T> UnsafeList<T> {
+    /// Create a new, empty `UnsafeList`.
+    pub const fn new() -> Self {
+        UnsafeList {
+            sentinel: Links {
+                next: Link::none(),
+                prev: Link::none(),
+            },
+            len: 0,
+            _elem_ty: PhantomData,
+        }
+    }
+
+    /// Returns the length of the list.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the list is empty, false otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns this list's sentinel address. Never dereferenced as `T` —
+    /// it only ever identifies "no real node here" when compared against
+    /// another pointer, or is used to reach `self.sentinel` directly.
+    fn sentinel_ptr(&self) -> *mut T {
+        &self.sentinel as *const Links<T> as *mut T
+    }
+
+    /// Borrows the first node of the list as an `Option`.
+    #[inline]
+    pub fn head(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            // Non-empty: `sentinel.next` is a real node, not the sentinel.
+            unsafe { self.sentinel.next.as_ref() }
+        }
+    }
+
+    /// Borrows the last node of the list as an `Option`.
+    #[inline]
+    pub fn tail(&self) -> Option<&T> {
+        if self.is_empty() {
+            None
+        } else {
+            unsafe { self.sentinel.prev.as_ref() }
+        }
+    }
+}
+
+impl<T> UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    /// Fixes the sentinel up to point at itself the first time the list
+    /// is touched, then returns its address.
+    fn init(&mut self) -> *mut T {
+        let ptr = self.sentinel_ptr();
+        if self.sentinel.next.0.is_none() {
+            let this = Link(Some(unsafe { NonNull::new_unchecked(ptr) }));
+            self.sentinel.next = this;
+            self.sentinel.prev = this;
+        }
+        ptr
+    }
+
+    /// Returns the address of `ptr`'s `Links`, whether `ptr` is a real
+    /// node or this list's sentinel.
+    ///
+    /// # Safety
+    /// `ptr` must either be this list's sentinel address, or point at a
+    /// live `T` that is (or was, up to the point of this call) linked
+    /// into this list.
+    unsafe fn links_of(&self, ptr: *mut T) -> *mut Links<T> {
+        if ptr == self.sentinel_ptr() {
+            &self.sentinel as *const Links<T> as *mut Links<T>
+        } else {
+            (*ptr).links_mut() as *mut Links<T>
+        }
+    }
+
+    /// Returns the node logically following the real node at `ptr`, or
+    /// `Link::none()` if `ptr` is the last node (i.e. its `next` is this
+    /// list's sentinel).
+    ///
+    /// # Safety
+    /// `ptr` must point at a live `T` that is a member of this list.
+    unsafe fn next_of(&self, ptr: *mut T) -> Link<T> {
+        let next = (*ptr).links().next;
+        if next.as_ptr() == Some(self.sentinel_ptr()) {
+            Link::none()
+        } else {
+            next
+        }
+    }
+
+    /// Returns the node logically preceding the real node at `ptr`, or
+    /// `Link::none()` if `ptr` is the first node (i.e. its `prev` is this
+    /// list's sentinel).
+    ///
+    /// # Safety
+    /// `ptr` must point at a live `T` that is a member of this list.
+    unsafe fn prev_of(&self, ptr: *mut T) -> Link<T> {
+        let prev = (*ptr).links().prev;
+        if prev.as_ptr() == Some(self.sentinel_ptr()) {
+            Link::none()
+        } else {
+            prev
+        }
+    }
+
+    /// Push a node to the head of the list.
+    pub fn push_front_node(&mut self, mut node: UnsafeRef<T>) {
+        unsafe {
+            let sentinel = self.init();
+            let old_head = (*self.links_of(sentinel)).next;
+            let old_head_ptr = old_head
+                .as_ptr()
+                .expect("sentinel always has a `next`, even when empty (itself)");
+
+            *node.links_mut() = Links {
+                next: old_head,
+                prev: Link(Some(NonNull::new_unchecked(sentinel))),
+            };
+            let node = Link::from_owning_ref(node);
+
+            (*self.links_of(old_head_ptr)).prev = node;
+            (*self.links_of(sentinel)).next = node;
+
+            self.len += 1;
+        }
+    }
+
+    /// Push a node to the tail of the list.
+    pub fn push_back_node(&mut self, mut node: UnsafeRef<T>) {
+        unsafe {
+            let sentinel = self.init();
+            let old_tail = (*self.links_of(sentinel)).prev;
+            let old_tail_ptr = old_tail
+                .as_ptr()
+                .expect("sentinel always has a `prev`, even when empty (itself)");
+
+            *node.links_mut() = Links {
+                prev: old_tail,
+                next: Link(Some(NonNull::new_unchecked(sentinel))),
+            };
+            let node = Link::from_owning_ref(node);
+
+            (*self.links_of(old_tail_ptr)).next = node;
+            (*self.links_of(sentinel)).prev = node;
+
+            self.len += 1;
+        }
+    }
+
+    /// Pop a node from the front of the list.
+    pub fn pop_front_node(&mut self) -> Option<UnsafeRef<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        unsafe {
+            let sentinel = self.init();
+            let head_ptr = (*self.links_of(sentinel))
+                .next
+                .as_ptr()
+                .expect("len > 0, so `sentinel.next` is a real node");
+            let next = (*self.links_of(head_ptr)).next;
+
+            (*self.links_of(next.as_ptr().expect("circular"))).prev =
+                Link(Some(NonNull::new_unchecked(sentinel)));
+            (*self.links_of(sentinel)).next = next;
+
+            self.len -= 1;
+            Some(UnsafeRef::from_ptr(head_ptr as *const T))
+        }
+    }
+
+    /// Pop a node from the back of the list.
+    pub fn pop_back_node(&mut self) -> Option<UnsafeRef<T>> {
+        if self.is_empty() {
+            return None;
+        }
+        unsafe {
+            let sentinel = self.init();
+            let tail_ptr = (*self.links_of(sentinel))
+                .prev
+                .as_ptr()
+                .expect("len > 0, so `sentinel.prev` is a real node");
+            let prev = (*self.links_of(tail_ptr)).prev;
+
+            (*self.links_of(prev.as_ptr().expect("circular"))).next =
+                Link(Some(NonNull::new_unchecked(sentinel)));
+            (*self.links_of(sentinel)).prev = prev;
+
+            self.len -= 1;
+            Some(UnsafeRef::from_ptr(tail_ptr as *const T))
+        }
+    }
+
+    /// Removes `node` from the middle of the list in `O(1)`, given only a
+    /// reference to that node.
+    ///
+    /// Splices `node` out by pointing its neighbors at each other, then
+    /// leaves `node`'s own `Links` pointing at itself, so that a second
+    /// call to `remove` with the same node no longer touches its former
+    /// neighbors. In debug builds such a second call fails an assertion;
+    /// in release builds it is not detected.
+    ///
+    /// # Safety
+    /// The caller must guarantee that `node` is currently a member of
+    /// *this* list. Removing a node that belongs to a different list, or
+    /// one that has already been removed, is undefined behavior.
+    pub unsafe fn remove(&mut self, node: UnsafeRef<T>) {
+        // Convert the owning ref into a raw-pointer `Link`, the same way
+        // `push_front_node`/`push_back_node` do, without dropping `node`.
+        let this = Link::from_owning_ref(node);
+        let ptr = this.as_ptr().expect("just created from an owning ref");
+
+        debug_assert!(
+            (*ptr).links().next.as_ptr() != Some(ptr) || (*ptr).links().prev.as_ptr() != Some(ptr),
+            "attempted to remove a node that was already removed from its list"
+        );
+
+        let next = (*ptr).links().next;
+        let prev = (*ptr).links().prev;
+
+        (*self.links_of(next.as_ptr().expect("circular"))).prev = prev;
+        (*self.links_of(prev.as_ptr().expect("circular"))).next = next;
+
+        self.len -= 1;
+
+        (*ptr).links_mut().next = this;
+        (*ptr).links_mut().prev = this;
+    }
+}
+
+/// Dropping an `UnsafeList` frees every node still linked into it, via
+/// [`UnsafeRef::into_box`], in `O(n)` and without recursion.
+///
+/// This walk is panic-safe: if a node's `T` destructor panics, a guard
+/// left behind on the stack keeps popping and freeing the remaining
+/// nodes while unwinding, so a single bad `Drop` impl can't leak the
+/// rest of the list.
+///
+/// [`UnsafeRef::into_box`]: ../struct.UnsafeRef.html#method.into_box
+impl<T> Drop for UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    fn drop(&mut self) {
+        struct DropGuard<'a, T: UnsafeListLinked>(&'a mut UnsafeList<T>);
+
+        impl<'a, T: UnsafeListLinked> Drop for DropGuard<'a, T> {
+            fn drop(&mut self) {
+                while let Some(node) = self.0.pop_front_node() {
+                    unsafe {
+                        drop(UnsafeRef::into_box(node));
+                    }
+                }
+            }
+        }
+
+        while let Some(node) = self.pop_front_node() {
+            let guard = DropGuard(self);
+            unsafe {
+                drop(UnsafeRef::into_box(node));
+            }
+            mem::forget(guard);
+        }
+    }
+}
+
+impl<T> Extend<UnsafeRef<T>> for UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    fn extend<I: IntoIterator<Item = UnsafeRef<T>>>(&mut self, iter: I) {
+        for node in iter {
+            self.push_back_node(node);
+        }
+    }
+}
+
+impl<T> FromIterator<UnsafeRef<T>> for UnsafeList<T>
+where
+    T: UnsafeListLinked,
+{
+    fn from_iter<I: IntoIterator<Item = UnsafeRef<T>>>(iter: I) -> Self {
+        let mut list = UnsafeList::new();
+        list.extend(iter);
+        list
+    }
+}
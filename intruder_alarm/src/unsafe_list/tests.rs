@@ -0,0 +1,161 @@
+use super::*;
+
+#[derive(Debug)]
+struct NumberedNode {
+    number: usize,
+    links: Links<NumberedNode>,
+}
+
+impl NumberedNode {
+    fn new(number: usize) -> Self {
+        NumberedNode {
+            number,
+            links: Links::default(),
+        }
+    }
+}
+
+impl UnsafeListLinked for NumberedNode {
+    fn links(&self) -> &Links<Self> {
+        &self.links
+    }
+
+    fn links_mut(&mut self) -> &mut Links<Self> {
+        &mut self.links
+    }
+}
+
+fn list_from(items: &[usize]) -> UnsafeList<NumberedNode> {
+    let mut list = UnsafeList::new();
+    for &i in items {
+        list.push_back_node(UnsafeRef::boxed(NumberedNode::new(i)));
+    }
+    list
+}
+
+#[test]
+fn empty_list_has_no_head_or_tail() {
+    let list: UnsafeList<NumberedNode> = UnsafeList::new();
+    assert!(list.is_empty());
+    assert_eq!(list.len(), 0);
+    assert!(list.head().is_none());
+    assert!(list.tail().is_none());
+}
+
+#[test]
+fn push_front_and_push_back_order() {
+    let mut list = list_from(&[1, 2]);
+    list.push_front_node(UnsafeRef::boxed(NumberedNode::new(0)));
+
+    assert_eq!(list.head().unwrap().number, 0);
+    assert_eq!(list.tail().unwrap().number, 2);
+    assert_eq!(list.len(), 3);
+}
+
+#[test]
+fn pop_front_and_pop_back() {
+    let mut list = list_from(&[0, 1, 2]);
+
+    assert_eq!(list.pop_front_node().unwrap().number, 0);
+    assert_eq!(list.pop_back_node().unwrap().number, 2);
+    assert_eq!(list.pop_front_node().unwrap().number, 1);
+    assert!(list.pop_front_node().is_none());
+    assert!(list.is_empty());
+}
+
+#[test]
+fn pop_on_an_empty_list_returns_none() {
+    let mut list: UnsafeList<NumberedNode> = UnsafeList::new();
+    assert!(list.pop_front_node().is_none());
+    assert!(list.pop_back_node().is_none());
+}
+
+#[test]
+fn iter_visits_items_front_to_back() {
+    let list = list_from(&[0, 1, 2]);
+    let items: Vec<usize> = list.iter().map(|n| n.number).collect();
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[test]
+fn iter_mut_allows_updating_items_in_place() {
+    let mut list = list_from(&[0, 1, 2]);
+    for node in list.iter_mut() {
+        node.number += 10;
+    }
+    let items: Vec<usize> = list.iter().map(|n| n.number).collect();
+    assert_eq!(items, vec![10, 11, 12]);
+}
+
+#[test]
+fn into_iter_drains_the_list() {
+    let list = list_from(&[0, 1, 2]);
+    let items: Vec<usize> = list.into_iter().map(|n| n.number).collect();
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[test]
+fn cursor_move_next_and_move_prev_wrap_through_the_ghost_position() {
+    let list = list_from(&[0, 1, 2]);
+    let mut cursor = list.cursor_front();
+
+    assert_eq!(cursor.current().unwrap().number, 0);
+    cursor.move_next();
+    cursor.move_next();
+    assert_eq!(cursor.current().unwrap().number, 2);
+
+    cursor.move_next();
+    assert!(cursor.current().is_none(), "cursor should be at the ghost position");
+
+    cursor.move_next();
+    assert_eq!(
+        cursor.current().unwrap().number,
+        0,
+        "moving past the ghost position should wrap to the front"
+    );
+}
+
+#[test]
+fn cursor_mut_remove_current_advances_to_the_next_node() {
+    let mut list = list_from(&[0, 1, 2]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+
+    let removed = cursor.remove_current().unwrap();
+    assert_eq!(removed.number, 1);
+    assert_eq!(cursor.current().unwrap().number, 2);
+    assert_eq!(list.len(), 2);
+}
+
+#[test]
+fn cursor_mut_insert_after_splices_in_after_current() {
+    let mut list = list_from(&[0, 2]);
+    let mut cursor = list.cursor_front_mut();
+
+    cursor.insert_after(UnsafeRef::boxed(NumberedNode::new(1)));
+
+    let items: Vec<usize> = list.iter().map(|n| n.number).collect();
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[test]
+fn cursor_mut_insert_before_splices_in_before_current() {
+    let mut list = list_from(&[0, 2]);
+    let mut cursor = list.cursor_front_mut();
+    cursor.move_next();
+
+    cursor.insert_before(UnsafeRef::boxed(NumberedNode::new(1)));
+
+    let items: Vec<usize> = list.iter().map(|n| n.number).collect();
+    assert_eq!(items, vec![0, 1, 2]);
+}
+
+#[test]
+fn drop_frees_every_remaining_node() {
+    // This mostly exists to run under Miri/a leak-checking allocator: if
+    // `Drop` stops walking early, or double-frees a node, this test either
+    // leaks or aborts instead of passing quietly.
+    let list = list_from(&[0, 1, 2, 3, 4]);
+    assert_eq!(list.len(), 5);
+    drop(list);
+}